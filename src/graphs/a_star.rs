@@ -0,0 +1,87 @@
+use super::directed_graph::DirectedGraph;
+use super::error::GraphError;
+use super::shortest_paths::Path;
+use super::weight::{MinScored, Weight};
+use std::collections::{BinaryHeap, HashMap};
+use std::hash::Hash;
+
+/// A* search. Finds the cheapest path from `start` to any vertex for which
+/// `is_goal` returns `true`.
+///
+/// `edge_cost` works out the cost of each edge from its start, end, and
+/// payload, and must never be negative. `heuristic` estimates the cost of
+/// getting from a vertex to the nearest goal. As long as it never
+/// overestimates (it's *admissible*), the path found is the cheapest one.
+/// The better the estimate, the fewer vertices need to be explored. A
+/// heuristic that always returns zero turns this into Dijkstra's algorithm.
+///
+/// Returns `Ok(None)` if no goal can be reached,
+/// `Err(GraphError::MissingSourceVertex)` if `start` is not in the graph,
+/// and `Err(GraphError::NegativeWeight)` if `edge_cost` returns a negative cost.
+pub fn a_star<'a, G, W, P, C, H>(
+    g: &'a G,
+    start: &G::Vertex,
+    mut is_goal: P,
+    mut edge_cost: C,
+    mut heuristic: H
+) -> Result<Option<Path<G::Vertex, W>>, GraphError>
+where
+    G: DirectedGraph,
+    W: Weight,
+    P: FnMut(&G::Vertex) -> bool,
+    C: FnMut(&G::Vertex, &G::Vertex, &G::Edge) -> W,
+    H: FnMut(&G::Vertex) -> W
+{
+    let start = g.find_vertex(start).ok_or(GraphError::MissingSourceVertex)?;
+
+    // Cheapest known cost of getting to each vertex, and where it came from
+    let mut best: HashMap<&'a G::Vertex, (W, Option<&'a G::Vertex>)> = HashMap::new();
+    // Ordered by the estimated total cost of a path through the vertex,
+    // along with the cost of getting to the vertex at the time it was pushed.
+    let mut open: BinaryHeap<MinScored<W, (W, &'a G::Vertex)>> = BinaryHeap::new();
+
+    best.insert(start, (W::zero(), None));
+    open.push(MinScored(heuristic(start), (W::zero(), start)));
+
+    while let Some(MinScored(_estimate, (cost, u))) = open.pop() {
+        // A cheaper way to get to `u` was found after this entry was
+        // pushed, so this entry is stale.
+        if best[u].0 < cost {
+            continue;
+        }
+
+        if is_goal(u) {
+            return Ok(Some(rebuild_path(&best, u, cost)));
+        }
+
+        for (v, payload) in g.edges_of(u).unwrap() {
+            let step = edge_cost(u, v, payload);
+            if step < W::zero() {
+                return Err(GraphError::NegativeWeight);
+            }
+            let candidate = cost + step;
+            let improved = match best.get(v) {
+                Some((known, _)) => candidate < *known,
+                None => true
+            };
+            if improved {
+                best.insert(v, (candidate, Some(u)));
+                open.push(MinScored(candidate + heuristic(v), (candidate, v)));
+            }
+        }
+    }
+
+    Ok(None)
+}
+
+/// Follows the recorded predecessors back from `goal` to the start
+fn rebuild_path<T: Clone + Eq + Hash, W>(best: &HashMap<&T, (W, Option<&T>)>, goal: &T, cost: W) -> Path<T, W> {
+    let mut vertices: Vec<T> = vec![goal.clone()];
+    let mut current = goal;
+    while let Some(previous) = best[current].1 {
+        vertices.push(previous.clone());
+        current = previous;
+    }
+    vertices.reverse();
+    Path { vertices, cost }
+}
@@ -0,0 +1,255 @@
+use super::bellman_ford::potentials;
+use super::dijkstra::search;
+use super::directed_graph::DirectedGraph;
+use super::shortest_paths::{Path, ShortestPathError};
+use super::weight::Weight;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// The shortest distance between every pair of vertices in a graph, plus
+/// a next-hop table to rebuild the paths themselves.
+///
+/// Like `ShortestPaths`, pairs of vertices with no path between them just
+/// don't have a distance.
+#[derive(Debug, Clone)]
+pub struct AllPairsShortestPaths<T: Clone + Eq + Hash, W> {
+    // distances[u][v] is the length of the shortest path from u to v
+    distances: HashMap<T, HashMap<T, W>>,
+    // next_hops[u][v] is the vertex right after u on the shortest path
+    // from u to v. There's no entry when u == v.
+    next_hops: HashMap<T, HashMap<T, T>>
+}
+
+impl<T: Clone + Eq + Hash, W: Copy> AllPairsShortestPaths<T, W> {
+    /// Returns the length of the shortest path from `u` to `v`, or `None`
+    /// if `v` can't be reached from `u`.
+    pub fn distance(&self, u: &T, v: &T) -> Option<W> {
+        self.distances.get(u)?.get(v).copied()
+    }
+
+    /// Returns every vertex reachable from `u`, along with its distance
+    /// from `u`. Or `None` if `u` isn't in the graph.
+    pub fn distances_from(&self, u: &T) -> Option<&HashMap<T, W>> {
+        self.distances.get(u)
+    }
+
+    /// Returns the first step on the shortest path from `u` to `v`. Or
+    /// `None` if `v` can't be reached from `u`, or if `u` and `v` are the same.
+    pub fn next_hop(&self, u: &T, v: &T) -> Option<&T> {
+        self.next_hops.get(u)?.get(v)
+    }
+
+    /// Rebuilds the shortest path from `u` to `v` by following the next
+    /// hops. Returns `None` if `v` can't be reached from `u`.
+    pub fn path(&self, u: &T, v: &T) -> Option<Path<T, W>> {
+        let cost = self.distance(u, v)?;
+        let mut vertices: Vec<T> = vec![u.clone()];
+        let mut current = u;
+        while current != v {
+            current = self.next_hop(current, v)?;
+            vertices.push(current.clone());
+        }
+        Some(Path { vertices, cost })
+    }
+}
+
+/// The Floyd-Warshall algorithm. Finds the shortest path between every
+/// pair of vertices by trying every vertex as a midpoint of every path.
+///
+/// Runs in O(V^3) time and uses O(V^2) space no matter how many edges there
+/// are, so it's best suited to dense graphs. Negative edge weights are fine,
+/// but if there is a negative cycle anywhere in the graph, it's returned as
+/// `Err(ShortestPathError::NegativeCycle)`.
+///
+/// The search stops as soon as some vertex can get back to itself for less
+/// than nothing, and the cycle is rebuilt from the next hops found so far.
+/// With floating point weights, that means a cycle that only adds up to a
+/// tiny bit below zero because of rounding still counts as negative.
+pub fn floyd_warshall<G, W>(g: &G) -> Result<AllPairsShortestPaths<G::Vertex, W>, ShortestPathError<G::Vertex>>
+where
+    G: DirectedGraph<Edge = W>,
+    W: Weight
+{
+    let vertices: Vec<&G::Vertex> = g.vertices_in_insertion_order();
+    let n = vertices.len();
+    let index: HashMap<&G::Vertex, usize> = vertices.iter().enumerate().map(|(i, v)| (*v, i)).collect();
+
+    // Flattened n by n matrices, (i, j) lives at i * n + j
+    let mut weights: Vec<Option<W>> = vec![None; n * n];
+    let mut next: Vec<Option<usize>> = vec![None; n * n];
+
+    for (i, u) in vertices.iter().enumerate() {
+        for (v, weight) in g.edges_of(u).unwrap() {
+            let j = index[v];
+            // There could be parallel edges, only the cheapest one matters
+            if weights[i * n + j].is_none_or(|w| *weight < w) {
+                weights[i * n + j] = Some(*weight);
+                next[i * n + j] = Some(j);
+            }
+        }
+    }
+    // A negative self-loop is a negative cycle all on its own
+    if let Some(i) = (0..n).find(|i| weights[i * n + i].is_some_and(|w| w < W::zero())) {
+        return Err(ShortestPathError::NegativeCycle(vec![vertices[i].clone()]));
+    }
+
+    let mut dist: Vec<Option<W>> = weights.clone();
+    for i in 0..n {
+        dist[i * n + i] = Some(W::zero());
+        next[i * n + i] = None;
+    }
+
+    for k in 0..n {
+        // A vertex that can get to k and back for less than nothing is on a
+        // negative cycle. Nothing through k has been updated yet, so the next
+        // hops still describe proper paths, and the cycle can be rebuilt.
+        let on_negative_cycle = (0..n).find(|i| match (dist[i * n + k], dist[k * n + i]) {
+            (Some(there), Some(back)) => there + back < W::zero(),
+            _ => false
+        });
+        if let Some(i) = on_negative_cycle {
+            let cycle = negative_cycle(&weights, &next, n, i, k);
+            return Err(ShortestPathError::NegativeCycle(cycle.into_iter().map(|v| vertices[v].clone()).collect()));
+        }
+
+        for i in 0..n {
+            let i_to_k = match dist[i * n + k] {
+                Some(d) => d,
+                None => continue
+            };
+            for j in 0..n {
+                if let Some(k_to_j) = dist[k * n + j] {
+                    let candidate = i_to_k + k_to_j;
+                    if dist[i * n + j].is_none_or(|d| candidate < d) {
+                        dist[i * n + j] = Some(candidate);
+                        next[i * n + j] = next[i * n + k];
+                    }
+                }
+            }
+        }
+    }
+
+    let mut result = AllPairsShortestPaths {
+        distances: HashMap::with_capacity(n),
+        next_hops: HashMap::with_capacity(n)
+    };
+    for (i, u) in vertices.iter().enumerate() {
+        let mut distances: HashMap<G::Vertex, W> = HashMap::new();
+        let mut next_hops: HashMap<G::Vertex, G::Vertex> = HashMap::new();
+        for (j, v) in vertices.iter().enumerate() {
+            if let Some(d) = dist[i * n + j] {
+                distances.insert((*v).clone(), d);
+            }
+            if let (Some(hop), true) = (next[i * n + j], i != j) {
+                next_hops.insert((*v).clone(), vertices[hop].clone());
+            }
+        }
+        result.distances.insert((*u).clone(), distances);
+        result.next_hops.insert((*u).clone(), next_hops);
+    }
+
+    Ok(result)
+}
+
+/// Rebuilds a negative cycle once Floyd-Warshall finds that `i` can get to
+/// `k` and back for less than nothing. Returns the indices of the vertices
+/// on the cycle, in order.
+///
+/// The paths from `i` to `k` and back are both simple, but they can cross
+/// each other, so together they aren't necessarily a simple cycle. The walk
+/// gets split into simple cycles wherever a vertex repeats, and the first
+/// negative one is returned. Splitting off a cycle that isn't negative
+/// leaves a walk that still is, so one of them has to be. If rounding gets
+/// in the way of that, the last cycle is returned anyway.
+fn negative_cycle<W: Weight>(weights: &[Option<W>], next: &[Option<usize>], n: usize, i: usize, k: usize) -> Vec<usize> {
+    // Safe to unwrap, every hop on the way from i to k and back is known,
+    // and every hop is an edge
+    let weight = |u: usize, v: usize| weights[u * n + v].unwrap();
+    let mut walk: Vec<usize> = vec![i];
+    for (from, to) in [(i, k), (k, i)] {
+        let mut current = from;
+        while current != to {
+            current = next[current * n + to].unwrap();
+            walk.push(current);
+        }
+    }
+    // The walk ends back at i, which is where it started
+    walk.pop();
+
+    let mut stack: Vec<usize> = Vec::with_capacity(walk.len());
+    let mut position: Vec<Option<usize>> = vec![None; n];
+    for v in walk {
+        let p = match position[v] {
+            None => {
+                position[v] = Some(stack.len());
+                stack.push(v);
+                continue;
+            }
+            Some(p) => p
+        };
+        let cycle = &stack[p..];
+        let total = (0..cycle.len())
+            .map(|c| weight(cycle[c], cycle[(c + 1) % cycle.len()]))
+            .fold(W::zero(), |total, w| total + w);
+        if total < W::zero() {
+            return cycle.to_vec();
+        }
+        // Not negative, so cut it out, leaving v where the cycle started
+        for u in stack.drain(p + 1..) {
+            position[u] = None;
+        }
+    }
+    // Whatever is left goes from i back around to i
+    stack
+}
+
+/// Johnson's algorithm. Finds the shortest path between every pair of
+/// vertices by running Dijkstra's algorithm from every vertex.
+///
+/// Negative edge weights are fine: Bellman-Ford is run once up front to
+/// find a potential for every vertex, and the edges are reweighted with
+/// those so that none of them are negative, without changing which paths
+/// are shortest. If there is a negative cycle anywhere in the graph, it's
+/// returned as `Err(ShortestPathError::NegativeCycle)`.
+///
+/// Runs in O(V*E log V), which beats Floyd-Warshall on sparse graphs.
+pub fn johnson<G, W>(g: &G) -> Result<AllPairsShortestPaths<G::Vertex, W>, ShortestPathError<G::Vertex>>
+where
+    G: DirectedGraph<Edge = W>,
+    W: Weight
+{
+    let h = potentials(g)?;
+    let reweighted = |u: &G::Vertex, v: &G::Vertex, weight: &W| {
+        let w = *weight + h[u] - h[v];
+        // The reweighted edges are never negative in theory, but floating
+        // point rounding doesn't always agree.
+        if w < W::zero() { W::zero() } else { w }
+    };
+
+    let mut result = AllPairsShortestPaths {
+        distances: HashMap::with_capacity(g.num_vertices()),
+        next_hops: HashMap::with_capacity(g.num_vertices())
+    };
+    for u in g.vertices() {
+        let found = search(g, u, None, reweighted)?;
+
+        // Undo the reweighting to get the real distances back
+        let distances: HashMap<G::Vertex, W> = found.distances.iter()
+            .map(|(v, d)| ((*v).clone(), *d - h[u] + h[*v]))
+            .collect();
+
+        // Vertices are settled closest first, so by the time a vertex is
+        // reached, the next hop towards its predecessor is already known.
+        let mut next_hops: HashMap<&G::Vertex, &G::Vertex> = HashMap::with_capacity(found.settled.len());
+        for v in found.settled.iter().skip(1) {
+            let previous = found.predecessors[v];
+            let hop = if previous == u { *v } else { next_hops[previous] };
+            next_hops.insert(v, hop);
+        }
+
+        result.distances.insert(u.clone(), distances);
+        result.next_hops.insert(u.clone(), next_hops.into_iter().map(|(v, hop)| (v.clone(), hop.clone())).collect());
+    }
+
+    Ok(result)
+}
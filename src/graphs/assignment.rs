@@ -0,0 +1,125 @@
+use super::weight::Weight;
+
+/// The result of `hungarian`: who got which job, and what it cost.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Assignment<W> {
+    /// Every `(row, column)` pair that was picked, sorted by row
+    pub pairs: Vec<(usize, usize)>,
+    /// The sum of the costs of every picked pair
+    pub total_cost: W
+}
+
+/// The Hungarian algorithm (aka Kuhn-Munkres). Solves the assignment
+/// problem: given the cost of giving each job (column) to each worker
+/// (row), finds the cheapest way to give every worker a different job.
+/// Runs in O(n^2 m), where n is the smaller and m the larger dimension.
+///
+/// The matrix doesn't have to be square. If there are more workers than
+/// jobs, every job is given to some worker and the rest of the workers get
+/// nothing, and the other way around. To maximize instead (say, a profit),
+/// negate the costs first.
+///
+/// Any `Weight` works, including unsigned integers and negative costs.
+/// Panics if the rows of `costs` aren't all the same length.
+pub fn hungarian<W: Weight>(costs: &[Vec<W>]) -> Assignment<W> {
+    let rows = costs.len();
+    let columns = costs.first().map_or(0, |row| row.len());
+    assert!(costs.iter().all(|row| row.len() == columns), "Every row of the cost matrix needs to be the same length");
+
+    // The algorithm below needs at least as many columns as rows
+    let mut pairs: Vec<(usize, usize)> = if rows <= columns {
+        solve(rows, columns, |i, j| costs[i][j])
+    } else {
+        solve(columns, rows, |i, j| costs[j][i])
+            .into_iter()
+            .map(|(j, i)| (i, j))
+            .collect()
+    };
+    pairs.sort_unstable();
+
+    let total_cost = pairs.iter().fold(W::zero(), |acc, &(i, j)| acc + costs[i][j]);
+    Assignment { pairs, total_cost }
+}
+
+/// The actual implementation, for an `n` by `m` matrix with `n <= m`.
+/// Returns `(row, column)` pairs, one for every row.
+///
+/// Adds the rows one at a time, each time finding the cheapest way to
+/// shuffle the existing assignment around to make room for it, using
+/// Dijkstra's algorithm on the reduced costs. Indices are off by one, with
+/// index 0 of the columns standing for "not assigned yet".
+fn solve<W, C>(n: usize, m: usize, cost: C) -> Vec<(usize, usize)>
+where
+    W: Weight,
+    C: Fn(usize, usize) -> W
+{
+    // The dual potentials. The usual formulation has column potentials that
+    // only ever go down, from zero, so they're stored negated here. That
+    // way nothing is ever negative, and unsigned costs work just fine.
+    // Every reduced cost `cost + column - row` stays non-negative.
+    let mut row_potentials: Vec<W> = vec![W::zero(); n + 1];
+    let mut column_potentials: Vec<W> = vec![W::zero(); m + 1];
+    // The row assigned to each column, 0 meaning none
+    let mut row_of: Vec<usize> = vec![0; m + 1];
+    // The previous column on the cheapest path to each column
+    let mut previous: Vec<usize> = vec![0; m + 1];
+
+    let mut min_reduced: Vec<Option<W>> = vec![None; m + 1];
+    let mut visited: Vec<bool> = vec![false; m + 1];
+    for i in 1..=n {
+        row_of[0] = i;
+        let mut column = 0;
+        min_reduced.iter_mut().for_each(|c| *c = None);
+        visited.iter_mut().for_each(|v| *v = false);
+
+        // Grow the tree of visited columns until it reaches a free column
+        loop {
+            visited[column] = true;
+            let row = row_of[column];
+            let mut delta: Option<W> = None;
+            let mut next = 0;
+            for j in 1..=m {
+                if visited[j] {
+                    continue;
+                }
+                let reduced = cost(row - 1, j - 1) + column_potentials[j] - row_potentials[row];
+                if min_reduced[j].is_none_or(|c| reduced < c) {
+                    min_reduced[j] = Some(reduced);
+                    previous[j] = column;
+                }
+                if delta.is_none_or(|d| min_reduced[j].is_some_and(|c| c < d)) {
+                    delta = min_reduced[j];
+                    next = j;
+                }
+            }
+
+            // There's always an unvisited column left, since n <= m
+            let delta = delta.unwrap();
+            for j in 0..=m {
+                if visited[j] {
+                    row_potentials[row_of[j]] = row_potentials[row_of[j]] + delta;
+                    column_potentials[j] = column_potentials[j] + delta;
+                } else {
+                    min_reduced[j] = min_reduced[j].map(|c| c - delta);
+                }
+            }
+
+            column = next;
+            if row_of[column] == 0 {
+                break;
+            }
+        }
+
+        // Flip the assignments along the path back to the new row
+        while column != 0 {
+            let before = previous[column];
+            row_of[column] = row_of[before];
+            column = before;
+        }
+    }
+
+    (1..=m)
+        .filter(|&j| row_of[j] != 0)
+        .map(|j| (row_of[j] - 1, j - 1))
+        .collect()
+}
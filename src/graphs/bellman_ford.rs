@@ -0,0 +1,134 @@
+use super::directed_graph::DirectedGraph;
+use super::error::GraphError;
+use super::shortest_paths::{ShortestPathError, ShortestPaths};
+use super::weight::Weight;
+use std::collections::HashMap;
+
+// Maps each vertex to the one right before it on its shortest path
+type Predecessors<'a, T> = HashMap<&'a T, &'a T>;
+
+/// The Bellman-Ford algorithm. Finds the shortest path from `source` to
+/// every other vertex of the graph, where the edge payloads are the weights.
+/// Runs in O(V*E), which is slower than Dijkstra's algorithm, but negative
+/// edge weights are fine.
+///
+/// If a cycle with a negative total weight can be reached from `source`,
+/// the shortest paths aren't well defined and the cycle is returned as
+/// `Err(ShortestPathError::NegativeCycle)`. Negative cycles that can't be
+/// reached from `source` don't matter and are ignored.
+pub fn bellman_ford<G, W>(g: &G, source: &G::Vertex) -> Result<ShortestPaths<G::Vertex, W>, ShortestPathError<G::Vertex>>
+where
+    G: DirectedGraph<Edge = W>,
+    W: Weight
+{
+    if !g.contains(source) {
+        return Err(GraphError::MissingSourceVertex.into());
+    }
+
+    let mut distances: HashMap<&G::Vertex, W> = HashMap::new();
+    distances.insert(source, W::zero());
+    let predecessors = relax_all(g, &mut distances)?;
+
+    let unreachable = g.vertices()
+        .filter(|v| !distances.contains_key(v))
+        .cloned()
+        .collect();
+    let distances = distances.into_iter().map(|(v, d)| (v.clone(), d)).collect();
+    let predecessors = predecessors.into_iter().map(|(v, u)| (v.clone(), u.clone())).collect();
+
+    Ok(ShortestPaths::new(source.clone(), distances, predecessors, unreachable))
+}
+
+/// Finds a "potential" for every vertex of the graph: the length of the
+/// shortest path ending at that vertex, starting from anywhere. It's the
+/// same as running the Bellman-Ford algorithm from an extra vertex with a
+/// zero weight edge to every other vertex, without having to add one.
+///
+/// The potentials are never positive, and for every edge `u -> v` with
+/// weight `w`, `w + potential(u) - potential(v)` is never negative. This is
+/// what lets Johnson's algorithm get rid of negative edge weights.
+pub(crate) fn potentials<G, W>(g: &G) -> Result<HashMap<&G::Vertex, W>, ShortestPathError<G::Vertex>>
+where
+    G: DirectedGraph<Edge = W>,
+    W: Weight
+{
+    let mut distances: HashMap<&G::Vertex, W> = g.vertices().map(|v| (v, W::zero())).collect();
+    relax_all(g, &mut distances)?;
+    Ok(distances)
+}
+
+/// Repeatedly relaxes every edge of the graph, starting from the given
+/// distances, until nothing improves. Returns the predecessor of every
+/// vertex whose distance was improved at least once.
+///
+/// Vertices without a distance are treated as unreachable (for now).
+/// If things are still improving after V rounds, there must be a negative
+/// cycle, and it's returned as the error.
+fn relax_all<'a, G>(g: &'a G, distances: &mut HashMap<&'a G::Vertex, G::Edge>) -> Result<Predecessors<'a, G::Vertex>, ShortestPathError<G::Vertex>>
+where
+    G: DirectedGraph,
+    G::Edge: Weight
+{
+    let mut predecessors: Predecessors<G::Vertex> = HashMap::new();
+
+    // A shortest path has at most V-1 edges, so V-1 rounds are always
+    // enough. The extra round is only there to spot negative cycles.
+    for round in 0..g.num_vertices() {
+        let mut last_relaxed: Option<&G::Vertex> = None;
+
+        for u in g.vertices() {
+            let u_distance = match distances.get(u) {
+                Some(d) => *d,
+                None => continue
+            };
+            for (v, weight) in g.edges_of(u).unwrap() {
+                let candidate = u_distance + *weight;
+                let improved = match distances.get(v) {
+                    Some(best) => candidate < *best,
+                    None => true
+                };
+                if improved {
+                    distances.insert(v, candidate);
+                    predecessors.insert(v, u);
+                    last_relaxed = Some(v);
+                }
+            }
+        }
+
+        match last_relaxed {
+            None => return Ok(predecessors),
+            Some(v) if round + 1 == g.num_vertices() => {
+                return Err(ShortestPathError::NegativeCycle(negative_cycle(g, &predecessors, v)));
+            }
+            Some(_) => {}
+        }
+    }
+
+    Ok(predecessors)
+}
+
+/// Recovers a negative cycle from the predecessors, given a vertex that
+/// was still being improved in the last round.
+///
+/// That vertex is either on a negative cycle or downstream of one, and
+/// stepping back V times along the predecessors is guaranteed to land
+/// on the cycle itself.
+fn negative_cycle<G>(g: &G, predecessors: &Predecessors<G::Vertex>, relaxed: &G::Vertex) -> Vec<G::Vertex>
+where
+    G: DirectedGraph
+{
+    let mut on_cycle: &G::Vertex = relaxed;
+    for _ in 0..g.num_vertices() {
+        on_cycle = predecessors[on_cycle];
+    }
+
+    let mut cycle: Vec<G::Vertex> = vec![on_cycle.clone()];
+    let mut current = predecessors[on_cycle];
+    while current != on_cycle {
+        cycle.push(current.clone());
+        current = predecessors[current];
+    }
+    // The walk went against the edges, so flip it back around
+    cycle.reverse();
+    cycle
+}
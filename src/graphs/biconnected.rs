@@ -0,0 +1,195 @@
+use super::graph::Graph;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Returns the articulation points (aka cut vertices) of the graph: the
+/// vertices whose removal would split their connected component in two or
+/// more. These are the single points of failure of a network.
+///
+/// The vertices are in insertion order. Runs in O(V+E).
+pub fn articulation_points<T, E>(g: &Graph<T, E>) -> Vec<T>
+where
+    T: Clone + Eq + Hash
+{
+    let search = search(g);
+    search.vertices.iter()
+        .zip(search.is_articulation_point.iter())
+        .filter(|(_v, is_articulation_point)| **is_articulation_point)
+        .map(|(v, _is_articulation_point)| (*v).clone())
+        .collect()
+}
+
+/// Returns the bridges of the graph: the edges whose removal would split
+/// their connected component in two. An edge with a parallel twin is never
+/// a bridge, since the twin still holds the two sides together.
+///
+/// Each bridge is `(u, v)`, where `u` is the end that the depth-first
+/// search got to first. Runs in O(V+E).
+pub fn bridges<T, E>(g: &Graph<T, E>) -> Vec<(T, T)>
+where
+    T: Clone + Eq + Hash
+{
+    let search = search(g);
+    search.bridges.iter()
+        .map(|&(u, v)| (search.vertices[u].clone(), search.vertices[v].clone()))
+        .collect()
+}
+
+/// Returns the biconnected components of the graph: the largest groups of
+/// edges where any two edges lie on a common simple cycle. Removing any
+/// single vertex leaves each component connected.
+///
+/// Each component is given as its vertices, in insertion order. Every
+/// edge is in exactly one component, but the articulation points show up
+/// in all of the components they join together. A bridge is a component
+/// of its own with two vertices, and a vertex without any edges (other
+/// than self-loops) is in no component at all. Runs in O(V+E).
+pub fn biconnected_components<T, E>(g: &Graph<T, E>) -> Vec<Vec<T>>
+where
+    T: Clone + Eq + Hash
+{
+    let search = search(g);
+    search.components.iter()
+        .map(|component| component.iter().map(|&v| search.vertices[v].clone()).collect())
+        .collect()
+}
+
+/// Everything the Hopcroft-Tarjan search finds, with the vertices as
+/// indices into `vertices`.
+struct Search<'a, T> {
+    vertices: Vec<&'a T>,
+    is_articulation_point: Vec<bool>,
+    bridges: Vec<(usize, usize)>,
+    components: Vec<Vec<usize>>
+}
+
+/// A vertex on the depth-first search stack
+struct Frame {
+    vertex: usize,
+    parent: Option<usize>,
+    /// Whether the edge back to the parent was skipped yet. Only one copy
+    /// of it gets skipped, so parallel edges count as a cycle.
+    skipped_parent: bool,
+    /// Index of the next neighbor to look at
+    next: usize,
+    children: usize
+}
+
+/// The Hopcroft-Tarjan algorithm. A depth-first search that keeps track of
+/// the earliest discovered vertex each subtree can reach with at most one
+/// back edge (its low point). A child whose subtree can't get above its
+/// parent means the parent is a cut vertex, and if the subtree can't even
+/// get back to the parent, the edge between them is a bridge.
+///
+/// Runs without recursion, since the search can be as deep as the graph
+/// is big.
+fn search<T, E>(g: &Graph<T, E>) -> Search<'_, T>
+where
+    T: Clone + Eq + Hash
+{
+    let vertices = g.vertices_in_insertion_order();
+    let index_of: HashMap<&T, usize> = vertices.iter()
+        .enumerate()
+        .map(|(i, v)| (*v, i))
+        .collect();
+    let adjacency: Vec<Vec<usize>> = vertices.iter()
+        .enumerate()
+        .map(|(i, v)| {
+            // Safe to unwrap, v came straight out of the graph
+            g.neighbors_of(v).unwrap()
+                .map(|w| index_of[w])
+                .filter(|j| *j != i)
+                .collect()
+        })
+        .collect();
+
+    let n = vertices.len();
+    let mut discovery: Vec<Option<usize>> = vec![None; n];
+    let mut low: Vec<usize> = vec![0; n];
+    let mut time = 0;
+    let mut is_articulation_point = vec![false; n];
+    let mut bridges: Vec<(usize, usize)> = Vec::new();
+    let mut components: Vec<Vec<usize>> = Vec::new();
+    // Edges of the components that aren't finished yet
+    let mut edges: Vec<(usize, usize)> = Vec::new();
+    // Which component each vertex was last added to, to avoid duplicates
+    let mut last_component: Vec<Option<usize>> = vec![None; n];
+
+    let mut stack: Vec<Frame> = Vec::new();
+    for root in 0..n {
+        if discovery[root].is_some() {
+            continue;
+        }
+        discovery[root] = Some(time);
+        low[root] = time;
+        time += 1;
+        stack.push(Frame { vertex: root, parent: None, skipped_parent: false, next: 0, children: 0 });
+
+        while let Some(frame) = stack.last_mut() {
+            let u = frame.vertex;
+            if let Some(&v) = adjacency[u].get(frame.next) {
+                frame.next += 1;
+                if frame.parent == Some(v) && !frame.skipped_parent {
+                    frame.skipped_parent = true;
+                    continue;
+                }
+                match discovery[v] {
+                    None => {
+                        frame.children += 1;
+                        edges.push((u, v));
+                        discovery[v] = Some(time);
+                        low[v] = time;
+                        time += 1;
+                        stack.push(Frame { vertex: v, parent: Some(u), skipped_parent: false, next: 0, children: 0 });
+                    }
+                    // A back edge to an ancestor. Edges to descendants were
+                    // already seen from the other end.
+                    Some(d) if d < discovery[u].unwrap() => {
+                        edges.push((u, v));
+                        low[u] = low[u].min(d);
+                    }
+                    Some(_) => ()
+                }
+                continue;
+            }
+
+            // Done with u, let its parent know how far up it can reach
+            let frame = stack.pop().unwrap();
+            let p = match frame.parent {
+                Some(p) => p,
+                None => {
+                    is_articulation_point[u] = frame.children > 1;
+                    continue;
+                }
+            };
+            low[p] = low[p].min(low[u]);
+            let p_discovery = discovery[p].unwrap();
+            if low[u] >= p_discovery {
+                // Everything pushed since the edge (p, u) is one component
+                if stack.len() > 1 {
+                    is_articulation_point[p] = true;
+                }
+                let id = components.len();
+                let mut component: Vec<usize> = Vec::new();
+                while let Some((a, b)) = edges.pop() {
+                    for x in [a, b] {
+                        if last_component[x] != Some(id) {
+                            last_component[x] = Some(id);
+                            component.push(x);
+                        }
+                    }
+                    if (a, b) == (p, u) {
+                        break;
+                    }
+                }
+                component.sort_unstable();
+                components.push(component);
+            }
+            if low[u] > p_discovery {
+                bridges.push((p, u));
+            }
+        }
+    }
+
+    Search { vertices, is_articulation_point, bridges, components }
+}
@@ -0,0 +1,325 @@
+use std::hash::Hash;
+use std::slice::Iter;
+use super::digraph::DiGraph;
+use super::directed_graph::DirectedGraph;
+use super::vertex_lookup::VertexLookup;
+
+/// A frozen, compact copy of a DiGraph in compressed sparse row form.
+///
+/// Every vertex is stored once and gets a dense `u32` index, in insertion
+/// order. Finding the index of a vertex only keeps its hash around, so that
+/// doesn't need a second copy of it. The edges of vertex `i` are
+/// `targets[offsets[i]..offsets[i + 1]]`, with their payloads at the same
+/// positions in `payloads`, so the edges never clone a vertex either. The same is kept for the edges coming
+/// into each vertex, so predecessors are as cheap to find as neighbors.
+/// The graph can't be changed once built; build a new one from the DiGraph
+/// instead.
+///
+/// Implements `DirectedGraph`, so the graph algorithms work on it the same
+/// way they do on a DiGraph.
+#[derive(Debug, Clone)]
+pub struct CsrGraph<T: Clone + Eq + Hash, E = ()> {
+    vertices: Vec<T>,
+    lookup: VertexLookup,
+    // Always one longer than `vertices`, so the last vertex has an end too
+    offsets: Vec<usize>,
+    targets: Vec<u32>,
+    payloads: Vec<E>,
+    // Same as `offsets` and `targets`, but for the edges coming in
+    in_offsets: Vec<usize>,
+    sources: Vec<u32>
+}
+
+impl<T: Clone + Eq + Hash, E: Clone> CsrGraph<T, E> {
+    /// Builds the CSR form of `g`. The vertices get their indices in
+    /// insertion order, and the edges out of each vertex keep the order they
+    /// were added in.
+    ///
+    /// Panics if `g` has more than `u32::MAX` vertices.
+    pub fn from_digraph(g: &DiGraph<T, E>) -> CsrGraph<T, E> {
+        let order = g.vertices_in_insertion_order();
+        assert!(order.len() <= u32::MAX as usize, "Too many vertices for u32 indices");
+
+        let vertices: Vec<T> = order.into_iter().cloned().collect();
+        let mut lookup = VertexLookup::default();
+        for (i, v) in vertices.iter().enumerate() {
+            lookup.insert(v, i as u32);
+        }
+
+        let mut offsets: Vec<usize> = Vec::with_capacity(vertices.len() + 1);
+        let mut targets: Vec<u32> = Vec::with_capacity(g.num_edges());
+        let mut payloads: Vec<E> = Vec::with_capacity(g.num_edges());
+        offsets.push(0);
+        for u in vertices.iter() {
+            // Safe to unwrap, u came from the graph
+            for (v, payload) in g.edges_of(u).unwrap() {
+                // Safe to unwrap, every neighbor is in the graph too
+                targets.push(lookup.get(&vertices, v).unwrap());
+                payloads.push(payload.clone());
+            }
+            offsets.push(targets.len());
+        }
+
+        // Count the edges coming into each vertex, then hand out the slots.
+        // Going through the sources in order keeps the predecessors of each
+        // vertex in insertion order.
+        let mut in_offsets: Vec<usize> = vec![0; vertices.len() + 1];
+        for v in targets.iter() {
+            in_offsets[*v as usize + 1] += 1;
+        }
+        for i in 0..vertices.len() {
+            in_offsets[i + 1] += in_offsets[i];
+        }
+        let mut next_slot: Vec<usize> = in_offsets[..vertices.len()].to_vec();
+        let mut sources: Vec<u32> = vec![0; targets.len()];
+        for u in 0..vertices.len() {
+            for v in targets[offsets[u]..offsets[u + 1]].iter() {
+                let slot = &mut next_slot[*v as usize];
+                sources[*slot] = u as u32;
+                *slot += 1;
+            }
+        }
+
+        CsrGraph { vertices, lookup, offsets, targets, payloads, in_offsets, sources }
+    }
+}
+
+impl<T: Clone + Eq + Hash, E: Clone> From<&DiGraph<T, E>> for CsrGraph<T, E> {
+    fn from(g: &DiGraph<T, E>) -> Self {
+        CsrGraph::from_digraph(g)
+    }
+}
+
+impl<T: Clone + Eq + Hash, E> CsrGraph<T, E> {
+    /// Returns the number of vertices present in the graph
+    pub fn num_vertices(&self) -> usize {
+        self.vertices.len()
+    }
+
+    /// Returns the number of edges present in the graph
+    pub fn num_edges(&self) -> usize {
+        self.targets.len()
+    }
+
+    /// Returns an iterator over every vertex in the graph, in index order,
+    /// which is also the order they were added to the original DiGraph
+    pub fn vertices(&self) -> Iter<'_, T> {
+        self.vertices.iter()
+    }
+
+    /// Returns the index of the vertex, or `None` if it is not in the graph
+    pub fn index_of(&self, node: &T) -> Option<u32> {
+        self.lookup.get(&self.vertices, node)
+    }
+
+    /// Returns the vertex with the given index, or `None` if there is no
+    /// such index
+    pub fn vertex(&self, index: u32) -> Option<&T> {
+        self.vertices.get(index as usize)
+    }
+
+    /// Returns `true` if the query vertex exists in the graph, `false` otherwise
+    pub fn contains(&self, node: &T) -> bool {
+        self.index_of(node).is_some()
+    }
+
+    /// Returns the graph's own copy of the query vertex, which lives as long
+    /// as the graph is borrowed. Or `None` if the vertex is not in the graph.
+    pub fn find_vertex(&self, node: &T) -> Option<&T> {
+        self.index_of(node).map(|index| &self.vertices[index as usize])
+    }
+
+    /// Returns the indices of the neighbors of the vertex with the given
+    /// index, in the order the edges were added.
+    ///
+    /// Panics if the index is out of bounds.
+    pub fn neighbor_indices(&self, index: u32) -> &[u32] {
+        let (start, end) = self.edge_range(index);
+        &self.targets[start..end]
+    }
+
+    /// Returns the indices of the vertices that have an edge to the vertex
+    /// with the given index, in the order they were added.
+    ///
+    /// Panics if the index is out of bounds.
+    pub fn predecessor_indices(&self, index: u32) -> &[u32] {
+        let index = index as usize;
+        &self.sources[self.in_offsets[index]..self.in_offsets[index + 1]]
+    }
+
+    /// Returns an iterator over the neighbors of a given vertex.
+    /// Or `None` if the vertex does not exist in the graph.
+    pub fn neighbors_of(&self, node: &T) -> Option<Neighbors<'_, T>> {
+        let index = self.index_of(node)?;
+        Some(Neighbors {
+            vertices: &self.vertices,
+            indices: self.neighbor_indices(index).iter()
+        })
+    }
+
+    /// Returns an iterator over the `(neighbor, payload)` pairs of every
+    /// edge that starts at the given vertex.
+    /// Or `None` if the vertex does not exist in the graph.
+    pub fn edges_of(&self, node: &T) -> Option<Edges<'_, T, E>> {
+        let (start, end) = self.edge_range(self.index_of(node)?);
+        Some(Edges {
+            vertices: &self.vertices,
+            targets: self.targets[start..end].iter(),
+            payloads: self.payloads[start..end].iter()
+        })
+    }
+
+    /// Returns an iterator over the vertices that have an edge to the given
+    /// vertex. Or `None` if the vertex does not exist in the graph.
+    pub fn predecessors_of(&self, node: &T) -> Option<Neighbors<'_, T>> {
+        let index = self.index_of(node)?;
+        Some(Neighbors {
+            vertices: &self.vertices,
+            indices: self.predecessor_indices(index).iter()
+        })
+    }
+
+    /// Returns the number of directed edges that start at the query vertex.
+    /// Or `None` if the query vertex does not exist in the graph.
+    /// This is an `O(1)` operation
+    pub fn out_degree(&self, node: &T) -> Option<usize> {
+        let (start, end) = self.edge_range(self.index_of(node)?);
+        Some(end - start)
+    }
+
+    /// Returns the number of directed edges that end at the query vertex.
+    /// Or `None` if the query vertex does not exist in the graph.
+    /// This is an `O(1)` operation
+    pub fn in_degree(&self, node: &T) -> Option<usize> {
+        self.index_of(node).map(|index| self.predecessor_indices(index).len())
+    }
+
+    /// Returns `true` if there is a directed edge from `u` to `v`, `false` otherwise
+    pub fn are_neighbors(&self, u: &T, v: &T) -> bool {
+        match (self.index_of(u), self.index_of(v)) {
+            (Some(u), Some(v)) => self.neighbor_indices(u).contains(&v),
+            _ => false
+        }
+    }
+
+    /// Returns the payload of the directed edge from `u` to `v`, or `None`
+    /// if there is no such edge. If there are several edges from `u` to `v`,
+    /// this is the payload of the one that was added first.
+    pub fn edge_weight(&self, u: &T, v: &T) -> Option<&E> {
+        let v = self.index_of(v)?;
+        let (start, end) = self.edge_range(self.index_of(u)?);
+        (start..end)
+            .find(|i| self.targets[*i] == v)
+            .map(|i| &self.payloads[i])
+    }
+
+    fn edge_range(&self, index: u32) -> (usize, usize) {
+        let index = index as usize;
+        (self.offsets[index], self.offsets[index + 1])
+    }
+}
+
+impl<T: Clone + Eq + Hash, E> DirectedGraph for CsrGraph<T, E> {
+    type Vertex = T;
+    type Edge = E;
+    type Vertices<'a> = Iter<'a, T> where Self: 'a;
+    type Neighbors<'a> = Neighbors<'a, T> where Self: 'a;
+    type Predecessors<'a> = Neighbors<'a, T> where Self: 'a;
+    type Edges<'a> = Edges<'a, T, E> where Self: 'a;
+
+    fn num_vertices(&self) -> usize {
+        self.num_vertices()
+    }
+
+    fn num_edges(&self) -> usize {
+        self.num_edges()
+    }
+
+    fn contains(&self, node: &T) -> bool {
+        self.contains(node)
+    }
+
+    fn find_vertex(&self, node: &T) -> Option<&T> {
+        self.find_vertex(node)
+    }
+
+    fn vertices(&self) -> Self::Vertices<'_> {
+        self.vertices()
+    }
+
+    fn vertices_in_insertion_order(&self) -> Vec<&T> {
+        // Already in order, no sorting needed
+        self.vertices.iter().collect()
+    }
+
+    fn insertion_rank(&self, node: &T) -> Option<usize> {
+        self.index_of(node).map(|index| index as usize)
+    }
+
+    fn neighbors_of(&self, node: &T) -> Option<Self::Neighbors<'_>> {
+        self.neighbors_of(node)
+    }
+
+    fn edges_of(&self, node: &T) -> Option<Self::Edges<'_>> {
+        self.edges_of(node)
+    }
+
+    fn predecessors_of(&self, node: &T) -> Option<Self::Predecessors<'_>> {
+        self.predecessors_of(node)
+    }
+
+    fn out_degree(&self, node: &T) -> Option<usize> {
+        self.out_degree(node)
+    }
+
+    fn in_degree(&self, node: &T) -> Option<usize> {
+        self.in_degree(node)
+    }
+
+    fn are_neighbors(&self, u: &T, v: &T) -> bool {
+        self.are_neighbors(u, v)
+    }
+}
+
+/// Iterator over the neighbors (or predecessors) of a vertex, which turns
+/// their indices back into vertices. See `CsrGraph::neighbors_of`.
+#[derive(Clone)]
+pub struct Neighbors<'a, T> {
+    vertices: &'a [T],
+    indices: Iter<'a, u32>
+}
+
+impl<'a, T> Iterator for Neighbors<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        self.indices.next().map(|v| &self.vertices[*v as usize])
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.indices.size_hint()
+    }
+}
+
+/// Iterator over the `(neighbor, payload)` pairs of the edges out of a
+/// vertex. See `CsrGraph::edges_of`.
+#[derive(Clone)]
+pub struct Edges<'a, T, E> {
+    vertices: &'a [T],
+    targets: Iter<'a, u32>,
+    payloads: Iter<'a, E>
+}
+
+impl<'a, T, E> Iterator for Edges<'a, T, E> {
+    type Item = (&'a T, &'a E);
+
+    fn next(&mut self) -> Option<(&'a T, &'a E)> {
+        let v = self.targets.next()?;
+        // Safe to unwrap, there's a payload for every target
+        Some((&self.vertices[*v as usize], self.payloads.next().unwrap()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.targets.size_hint()
+    }
+}
@@ -1,41 +1,69 @@
 use std::collections::{HashMap, HashSet};
+use std::collections::hash_map::Keys;
 use std::hash::Hash;
 use std::slice::Iter;
 use std::fmt::Debug;
+use std::iter::Map;
+use super::directed_graph::DirectedGraph;
+use super::error::GraphError;
 
 /// Very simple DiGraph implementation
 ///
 /// T is the type of the nodes. No duplicate nodes may exist
 /// in the graph.
 ///
+/// E is the type of the data attached to each edge, such as a weight,
+/// label, or capacity. It defaults to `()`, which is a plain unweighted
+/// graph, and that's the only case where `add_edge` is available.
+/// Otherwise every edge gets its payload through `add_edge_with`.
+///
 /// Hopefully this implementation will be updated
 /// as I create more graph algorithms and understand
 /// what is important in a graph data structure.
 /// Notably, this implementation is very space inefficient...
-pub struct DiGraph<T: Clone + Eq + Hash> {
+pub struct DiGraph<T: Clone + Eq + Hash, E = ()> {
     // Consider making this <T, Vec<&T>> to save space?
     // Test first! Refactor later!
-    edge_map: HashMap<T, Vec<T>>
+    edge_map: HashMap<T, Vec<(T, E)>>,
+    // Mirror of `edge_map` with every edge flipped around, so that
+    // questions about incoming edges don't need to scan the whole graph.
+    // The payloads only live in `edge_map`.
+    reverse_map: HashMap<T, Vec<T>>,
+    // When each vertex was added, relative to the others. HashMap iteration
+    // order is random, so this is the only way to get a repeatable order.
+    insertion_ranks: HashMap<T, usize>,
+    next_rank: usize
 }
 
-impl<T: Clone + Eq + Hash> Default for DiGraph<T> {
+impl<T: Clone + Eq + Hash, E> Default for DiGraph<T, E> {
     fn default() -> Self {
         Self::new()
     }
 }
 
-
 impl<T: Clone + Eq + Hash> DiGraph<T> {
+    /// Adds a directed edge between `u` and `v`. Returns `Ok(())` if the
+    /// operation was successful, but `Err(GraphError::MissingSourceVertex)`
+    /// if `u` does not exist in the graph.
+    pub fn add_edge(&mut self, u: &T, v: &T) -> Result<(), GraphError> {
+        self.add_edge_with(u, v, ())
+    }
+}
+
+impl<T: Clone + Eq + Hash, E> DiGraph<T, E> {
     /// Constructs a new, empty DiGraph
-    pub fn new() -> DiGraph<T> {
+    pub fn new() -> DiGraph<T, E> {
         DiGraph {
-            edge_map: HashMap::new()
+            edge_map: HashMap::new(),
+            reverse_map: HashMap::new(),
+            insertion_ranks: HashMap::new(),
+            next_rank: 0
         }
     }
 
     /// Prints out the backing HashMap for the DiGraph
     /// Your node type T must also implement Debug in order to use this function
-    pub fn debug_print(g: DiGraph<T>) where T: Debug {
+    pub fn debug_print(g: DiGraph<T, E>) where T: Debug, E: Debug {
         println!("{:#?}", g.edge_map);
     }
 
@@ -52,26 +80,61 @@ impl<T: Clone + Eq + Hash> DiGraph<T> {
         })
     }
 
-    /// Adds a new, unconnected, vertex to the graph
-    pub fn add_vertex(&mut self, node: T) -> Result<(), String> {
+    /// Returns an iterator over every vertex in the graph, in no particular order.
+    pub fn vertices(&self) -> Keys<'_, T, Vec<(T, E)>> {
+        self.edge_map.keys()
+    }
+
+    /// Adds a new, unconnected, vertex to the graph.
+    /// Returns `Err(GraphError::DuplicateVertex)` if the vertex already exists.
+    pub fn add_vertex(&mut self, node: T) -> Result<(), GraphError> {
         if self.edge_map.contains_key(&node) {
-            // again, proper err type would be better here
-            Err(String::from("Attempted to insert duplicate node"))
+            Err(GraphError::DuplicateVertex)
         } else {
-            self.edge_map.insert(node, Vec::new());
+            self.insert_vertex(node);
             Ok(())
         }
     }
 
+    /// Adds a vertex that is known not to be in the graph yet
+    fn insert_vertex(&mut self, node: T) {
+        self.insertion_ranks.insert(node.clone(), self.next_rank);
+        self.next_rank += 1;
+        self.reverse_map.insert(node.clone(), Vec::new());
+        self.edge_map.insert(node, Vec::new());
+    }
+
+    /// Returns a number that orders the vertices by when they were added to
+    /// the graph, or `None` if the vertex does not exist in the graph.
+    /// Vertices that were added earlier have smaller ranks. A vertex that is
+    /// removed and added again is ranked as if it were brand new.
+    pub fn insertion_rank(&self, node: &T) -> Option<usize> {
+        self.insertion_ranks.get(node).copied()
+    }
+
+    /// Returns every vertex in the graph, ordered by `insertion_rank`.
+    /// This is an `O(V log V)` operation, as the vertices need to be sorted.
+    pub fn vertices_in_insertion_order(&self) -> Vec<&T> {
+        let mut vertices: Vec<&T> = self.edge_map.keys().collect();
+        vertices.sort_by_cached_key(|v| self.insertion_ranks[*v]);
+        vertices
+    }
+
     /// Returns `true` if the query vertex exists in the graph, `false` otherwise
     pub fn contains(&self, node: &T) -> bool {
         self.edge_map.contains_key(node)
     }
 
+    /// Returns the graph's own copy of the query vertex, which lives as long
+    /// as the graph is borrowed. Or `None` if the vertex is not in the graph.
+    pub fn find_vertex(&self, node: &T) -> Option<&T> {
+        self.edge_map.get_key_value(node).map(|(v, _edges)| v)
+    }
+
     /// Returns `true` if there is a directed edge from `u` to `v`, `false` otherwise
     pub fn are_neighbors(&self, u: &T, v: &T) -> bool {
         if let Some(edges) = self.edge_map.get(u) {
-            edges.contains(v)
+            edges.iter().any(|(w, _payload)| w == v)
         } else {
             false
         }
@@ -79,10 +142,33 @@ impl<T: Clone + Eq + Hash> DiGraph<T> {
 
     /// Returns an iterator over the neighbors of a given vertex.
     /// Or `None` if the vertex does not exist in the graph.
-    pub fn neighbors_of(&self, node: &T) -> Option<Iter<T>> {
+    pub fn neighbors_of(&self, node: &T) -> Option<Neighbors<'_, T, E>> {
+        self.edge_map.get(node).map(|edges| Neighbors { edges: edges.iter() })
+    }
+
+    /// Returns an iterator over the `(neighbor, payload)` pairs of every
+    /// edge that starts at the given vertex.
+    /// Or `None` if the vertex does not exist in the graph.
+    pub fn edges_of(&self, node: &T) -> Option<Iter<'_, (T, E)>> {
         self.edge_map.get(node).map(|edges| edges.iter())
     }
 
+    /// Returns the payload of the directed edge from `u` to `v`, or `None`
+    /// if there is no such edge. If there are several edges from `u` to `v`,
+    /// this is the payload of the one that was added first.
+    pub fn edge_weight(&self, u: &T, v: &T) -> Option<&E> {
+        self.edge_map.get(u)?
+            .iter()
+            .find(|(w, _payload)| w == v)
+            .map(|(_w, payload)| payload)
+    }
+
+    /// Returns an iterator over the vertices that have an edge ending at
+    /// the given vertex. Or `None` if the vertex does not exist in the graph.
+    pub fn predecessors_of(&self, node: &T) -> Option<Iter<'_, T>> {
+        self.reverse_map.get(node).map(|edges| edges.iter())
+    }
+
     /// Returns the number of directed edges that start at the query vertex.
     /// Or `None` if the query vertex does not exist in the graph.
     /// This is an `O(1)` operation
@@ -92,28 +178,15 @@ impl<T: Clone + Eq + Hash> DiGraph<T> {
 
     /// Returns the number of directed edges that end at the query vertex.
     /// Or None if the query vertex does not exist in the graph.
-    /// This is an `O(E)` operation
+    /// This is an `O(1)` operation
     pub fn in_degree(&self, node: &T) -> Option<usize> {
-        // If the target node isn't even in the graph, don't bother checking anything
-        if self.edge_map.get(node).is_none() {
-            None
-        } else {
-            // Otherwise, count up how many times the target node appears in all the edges
-            Some(self.edge_map.iter().fold(0, |acc, (_node, edges)| {
-                if edges.contains(node) {
-                    acc + 1
-                } else {
-                    acc
-                }
-            }))
-        }
+        self.reverse_map.get(node).map(|edges| edges.len())
     }
 
-    /// Adds a directed edge between `u` and `v`. Returns `Ok(())` if the
-    /// operation was successful, but Err if `u` does not exist in the graph.
-    pub fn add_edge(&mut self, u: &T, v: &T) -> Result<(), String> {
-        // Ideally, use a proper std::Error implementing type
-        // Or revert back to &'static str
+    /// Adds a directed edge between `u` and `v` carrying `payload`.
+    /// Returns `Ok(())` if the operation was successful, but
+    /// `Err(GraphError::MissingSourceVertex)` if `u` does not exist in the graph.
+    pub fn add_edge_with(&mut self, u: &T, v: &T, payload: E) -> Result<(), GraphError> {
         if self.edge_map.contains_key(u) {
             // Notes:
             // 1. It is safe to unwrap these values because I'm directly checking
@@ -124,67 +197,175 @@ impl<T: Clone + Eq + Hash> DiGraph<T> {
             //    two different times and non of those references can overlap.
             if !self.edge_map.contains_key(v) {
                 // We HAVE to clone here because both Vec#push
-                // and add_vertex take ownership of the value.
-                self.edge_map.insert(v.clone(), Vec::new());
+                // and insert_vertex take ownership of the value.
+                self.insert_vertex(v.clone());
             }
 
             let target_edges = self.edge_map.get_mut(u).unwrap();
-            target_edges.push(v.clone());
+            target_edges.push((v.clone(), payload));
+            let source_edges = self.reverse_map.get_mut(v).unwrap();
+            source_edges.push(u.clone());
             Ok(())
         } else {
-            Err(String::from("Start vertex of edge not present!"))
+            Err(GraphError::MissingSourceVertex)
         }
     }
 
     /// Helper function for removing the first occurrence of the
-    /// value `target` in the provided `list`.
-    fn remove_by_value(list: &mut Vec<T>, target: &T) {
+    /// value `target` in the provided `list`. Returns `true` if
+    /// something was actually removed.
+    fn remove_by_value(list: &mut Vec<T>, target: &T) -> bool {
         match list.iter().position(|i| target.eq(i)) {
-            None => {}
+            None => false,
+            Some(idx) => {
+                list.remove(idx);
+                true
+            }
+        }
+    }
+
+    /// Same as `remove_by_value`, but for a list of outgoing edges
+    fn remove_edge_to(list: &mut Vec<(T, E)>, target: &T) -> bool {
+        match list.iter().position(|(i, _payload)| target.eq(i)) {
+            None => false,
             Some(idx) => {
                 list.remove(idx);
+                true
             }
         }
     }
 
     /// Removes the directed edge between `u` and `v`, if it exists.
     /// If the edge does not exist, this operation is idempotent.
+    /// If there are several edges from `u` to `v`, only the one that
+    /// was added first is removed.
     pub fn remove_edge(&mut self, u: &T, v: &T) {
         // Not clear to me if a return value is worthwhile here
         if let Some(target_edges) = self.edge_map.get_mut(u) {
-            DiGraph::remove_by_value(target_edges, v);
+            if DiGraph::remove_edge_to(target_edges, v) {
+                // The edge existed, so `v` is guaranteed to be in the graph
+                DiGraph::<T, E>::remove_by_value(self.reverse_map.get_mut(v).unwrap(), u);
+            }
         }
     }
 
     /// Removes the target vertex and all of its incoming edges
     /// (all edges that end at the target vertex) from the graph.
     ///
-    /// This is an `O(E)` operation.
+    /// This is proportional to the degree of the target vertex (and
+    /// the degrees of its neighbors), rather than the size of the graph.
     pub fn remove_vertex(&mut self, target: &T) {
-        // This is a relatively expensive operation: O(V + E)
-        // First, remove all directed edges going TO the target
-        for (_node, edges) in self.edge_map.iter_mut() {
-            DiGraph::remove_by_value(edges, target)
+        // First, remove the vertex and all of its outgoing edges,
+        // making sure each successor forgets about the target.
+        let successors = match self.edge_map.remove(target) {
+            Some(edges) => edges,
+            None => return
+        };
+        for (v, _payload) in successors.iter() {
+            if let Some(sources) = self.reverse_map.get_mut(v) {
+                DiGraph::<T, E>::remove_by_value(sources, target);
+            }
+        }
+
+        // Then, remove all directed edges going TO the target.
+        // Any self-loops were already handled above.
+        let predecessors = self.reverse_map.remove(target).unwrap_or_default();
+        self.insertion_ranks.remove(target);
+        for u in predecessors.iter() {
+            if let Some(edges) = self.edge_map.get_mut(u) {
+                DiGraph::remove_edge_to(edges, target);
+            }
         }
-        // Then, remove the vertex and all of its outgoing edges
-        self.edge_map.remove(target);
     }
 
     /// Returns all of the vertices in the graph that have an
     /// in-degree of 0.
     ///
-    /// This is an `O(V+E)` operation and clones all keys.
+    /// This is an `O(V)` operation and clones the matching keys.
     pub fn get_source_vertices(&self) -> HashSet<T> {
-        // Magic generic method for converting iterators into collections
-        // The type &T is significant because we don't want to copy all the keys,
-        // nor do we want to take ownership of the keys in the hashmap.
-        let mut vertices: HashSet<T> = self.edge_map.keys().map(|k| k.to_owned()).collect::<HashSet<_>>();
-        for (_node, edges) in self.edge_map.iter() {
-            for end_vertex in edges {
-                // It is idempotent to remove something not in the set
-                vertices.remove(end_vertex);
-            }
-        }
-        vertices
+        self.reverse_map.iter()
+            .filter(|(_node, sources)| sources.is_empty())
+            .map(|(node, _sources)| node.to_owned())
+            .collect()
+    }
+}
+
+impl<T: Clone + Eq + Hash, E> DirectedGraph for DiGraph<T, E> {
+    type Vertex = T;
+    type Edge = E;
+    type Vertices<'a> = Keys<'a, T, Vec<(T, E)>> where Self: 'a;
+    type Neighbors<'a> = Neighbors<'a, T, E> where Self: 'a;
+    type Predecessors<'a> = Iter<'a, T> where Self: 'a;
+    type Edges<'a> = Map<Iter<'a, (T, E)>, fn(&'a (T, E)) -> (&'a T, &'a E)> where Self: 'a;
+
+    fn num_vertices(&self) -> usize {
+        self.num_vertices()
+    }
+
+    fn num_edges(&self) -> usize {
+        self.num_edges()
+    }
+
+    fn contains(&self, node: &T) -> bool {
+        self.contains(node)
+    }
+
+    fn find_vertex(&self, node: &T) -> Option<&T> {
+        self.find_vertex(node)
+    }
+
+    fn vertices(&self) -> Self::Vertices<'_> {
+        self.vertices()
+    }
+
+    fn vertices_in_insertion_order(&self) -> Vec<&T> {
+        self.vertices_in_insertion_order()
+    }
+
+    fn insertion_rank(&self, node: &T) -> Option<usize> {
+        self.insertion_rank(node)
+    }
+
+    fn neighbors_of(&self, node: &T) -> Option<Self::Neighbors<'_>> {
+        self.neighbors_of(node)
     }
-}
\ No newline at end of file
+
+    fn edges_of(&self, node: &T) -> Option<Self::Edges<'_>> {
+        self.edges_of(node).map(|edges| edges.map((|(v, payload)| (v, payload)) as fn(&(T, E)) -> (&T, &E)))
+    }
+
+    fn predecessors_of(&self, node: &T) -> Option<Self::Predecessors<'_>> {
+        self.predecessors_of(node)
+    }
+
+    fn out_degree(&self, node: &T) -> Option<usize> {
+        self.out_degree(node)
+    }
+
+    fn in_degree(&self, node: &T) -> Option<usize> {
+        self.in_degree(node)
+    }
+
+    fn are_neighbors(&self, u: &T, v: &T) -> bool {
+        self.are_neighbors(u, v)
+    }
+}
+
+/// Iterator over the neighbors of a vertex, which skips over the edge
+/// payloads. See `DiGraph::neighbors_of`.
+#[derive(Clone)]
+pub struct Neighbors<'a, T, E> {
+    edges: Iter<'a, (T, E)>
+}
+
+impl<'a, T, E> Iterator for Neighbors<'a, T, E> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        self.edges.next().map(|(v, _payload)| v)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.edges.size_hint()
+    }
+}
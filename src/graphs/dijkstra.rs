@@ -0,0 +1,134 @@
+use super::directed_graph::DirectedGraph;
+use super::error::GraphError;
+use super::shortest_paths::{Path, ShortestPaths};
+use super::weight::{MinScored, Weight};
+use std::collections::{BinaryHeap, HashMap};
+
+/// Dijkstra's algorithm. Finds the shortest path from `source` to every
+/// other vertex of the graph, where the edge payloads are the weights.
+/// Runs in O((V+E) log V).
+///
+/// Every edge weight must be non-negative. Returns
+/// `Err(GraphError::NegativeWeight)` as soon as a negative weight is found,
+/// and `Err(GraphError::MissingSourceVertex)` if `source` is not in the graph.
+pub fn dijkstra<G, W>(g: &G, source: &G::Vertex) -> Result<ShortestPaths<G::Vertex, W>, GraphError>
+where
+    G: DirectedGraph<Edge = W>,
+    W: Weight
+{
+    let search = search(g, source, None, |_u, _v, weight| *weight)?;
+
+    let unreachable = g.vertices()
+        .filter(|v| !search.distances.contains_key(v))
+        .cloned()
+        .collect();
+    let distances = search.distances.into_iter().map(|(v, d)| (v.clone(), d)).collect();
+    let predecessors = search.predecessors.into_iter().map(|(v, u)| (v.clone(), u.clone())).collect();
+
+    Ok(ShortestPaths::new(source.clone(), distances, predecessors, unreachable))
+}
+
+/// Dijkstra's algorithm, but only for the single shortest path from `source`
+/// to `target`. The search stops as soon as `target` is reached, so this can
+/// be a lot faster than `dijkstra` when the target is close to the source.
+///
+/// Returns `Ok(None)` if there is no path from `source` to `target`.
+/// Fails the same way `dijkstra` does, and also with
+/// `Err(GraphError::MissingTargetVertex)` if `target` is not in the graph.
+pub fn dijkstra_to<G, W>(g: &G, source: &G::Vertex, target: &G::Vertex) -> Result<Option<Path<G::Vertex, W>>, GraphError>
+where
+    G: DirectedGraph<Edge = W>,
+    W: Weight
+{
+    if !g.contains(target) {
+        return Err(GraphError::MissingTargetVertex);
+    }
+    let search = search(g, source, Some(target), |_u, _v, weight| *weight)?;
+
+    let cost = match search.distances.get(target) {
+        Some(cost) => *cost,
+        None => return Ok(None)
+    };
+    let mut vertices: Vec<G::Vertex> = vec![target.clone()];
+    let mut current = target;
+    while let Some(previous) = search.predecessors.get(current) {
+        vertices.push((*previous).clone());
+        current = previous;
+    }
+    vertices.reverse();
+
+    Ok(Some(Path { vertices, cost }))
+}
+
+/// Everything Dijkstra's algorithm figured out, still borrowing from the graph
+pub(crate) struct Search<'a, T, W> {
+    /// Final distances of every vertex that was settled
+    pub distances: HashMap<&'a T, W>,
+    /// The previous vertex on the shortest path, for every settled vertex but the source
+    pub predecessors: HashMap<&'a T, &'a T>,
+    /// Every settled vertex, in the order they were settled (closest first)
+    pub settled: Vec<&'a T>
+}
+
+/// The actual implementation of Dijkstra's algorithm. Stops early once
+/// `target` is settled, if there is one.
+///
+/// The weight of each edge is worked out by `cost`, given the start and
+/// end of the edge and its payload. That way callers can search a graph
+/// with adjusted weights without building a whole new graph.
+pub(crate) fn search<'a, G, W, F>(g: &'a G, source: &G::Vertex, target: Option<&G::Vertex>, cost: F) -> Result<Search<'a, G::Vertex, W>, GraphError>
+where
+    G: DirectedGraph,
+    W: Weight,
+    F: Fn(&G::Vertex, &G::Vertex, &G::Edge) -> W
+{
+    let source = g.find_vertex(source).ok_or(GraphError::MissingSourceVertex)?;
+
+    // Best distance found so far, which is only final once the vertex is settled
+    let mut tentative: HashMap<&G::Vertex, (W, Option<&G::Vertex>)> = HashMap::new();
+    let mut distances: HashMap<&G::Vertex, W> = HashMap::new();
+    let mut predecessors: HashMap<&G::Vertex, &G::Vertex> = HashMap::new();
+    let mut settled: Vec<&G::Vertex> = Vec::new();
+    let mut queue: BinaryHeap<MinScored<W, &G::Vertex>> = BinaryHeap::new();
+
+    tentative.insert(source, (W::zero(), None));
+    queue.push(MinScored(W::zero(), source));
+
+    while let Some(MinScored(distance, u)) = queue.pop() {
+        // The queue can hold stale entries for vertices that were
+        // improved upon after being pushed, skip those.
+        if distances.contains_key(u) {
+            continue;
+        }
+        distances.insert(u, distance);
+        if let Some(previous) = tentative[u].1 {
+            predecessors.insert(u, previous);
+        }
+        settled.push(u);
+
+        if target == Some(u) {
+            break;
+        }
+
+        for (v, payload) in g.edges_of(u).unwrap() {
+            let weight = cost(u, v, payload);
+            if weight < W::zero() {
+                return Err(GraphError::NegativeWeight);
+            }
+            if distances.contains_key(v) {
+                continue;
+            }
+            let candidate = distance + weight;
+            let improved = match tentative.get(v) {
+                Some((best, _)) => candidate < *best,
+                None => true
+            };
+            if improved {
+                tentative.insert(v, (candidate, Some(u)));
+                queue.push(MinScored(candidate, v));
+            }
+        }
+    }
+
+    Ok(Search { distances, predecessors, settled })
+}
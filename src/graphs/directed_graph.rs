@@ -0,0 +1,83 @@
+use std::hash::Hash;
+
+/// Read-only access to a directed graph, which is all the algorithms that
+/// take a graph as input need. Implementing this for a graph type lets
+/// those algorithms run on it. The ones that build a new graph, like
+/// `transitive_closure`, still hand back a DiGraph.
+///
+/// Algorithms that read the edge payloads as weights or capacities also
+/// need `Edge` to be a `Weight`, which rules out graphs without payloads.
+///
+/// Each graph type also has these as inherent methods, so there's no need
+/// to import this trait just to use a graph directly. Only generic code
+/// needs it.
+pub trait DirectedGraph {
+    /// The type of the vertices
+    type Vertex: Clone + Eq + Hash;
+    /// The type of the data attached to each edge, such as a weight
+    type Edge;
+    /// Iterator over every vertex of the graph
+    type Vertices<'a>: Iterator<Item = &'a Self::Vertex> where Self: 'a;
+    /// Iterator over the neighbors of a vertex
+    type Neighbors<'a>: Iterator<Item = &'a Self::Vertex> where Self: 'a;
+    /// Iterator over the vertices that have an edge to a vertex
+    type Predecessors<'a>: Iterator<Item = &'a Self::Vertex> where Self: 'a;
+    /// Iterator over the `(neighbor, payload)` pairs of every edge that
+    /// starts at a vertex
+    type Edges<'a>: Iterator<Item = (&'a Self::Vertex, &'a Self::Edge)> where Self: 'a;
+
+    /// Returns the number of vertices present in the graph
+    fn num_vertices(&self) -> usize;
+
+    /// Returns the number of edges present in the graph
+    fn num_edges(&self) -> usize;
+
+    /// Returns `true` if the query vertex exists in the graph, `false` otherwise
+    fn contains(&self, node: &Self::Vertex) -> bool;
+
+    /// Returns the graph's own copy of the query vertex, which lives as long
+    /// as the graph is borrowed. Or `None` if the vertex is not in the graph.
+    fn find_vertex(&self, node: &Self::Vertex) -> Option<&Self::Vertex>;
+
+    /// Returns an iterator over every vertex in the graph, in no particular order
+    fn vertices(&self) -> Self::Vertices<'_>;
+
+    /// Returns every vertex in the graph, ordered by when they were added.
+    /// The algorithms use this whenever they need a repeatable order.
+    fn vertices_in_insertion_order(&self) -> Vec<&Self::Vertex>;
+
+    /// Returns a number that orders the vertex by when it was added: the
+    /// earlier a vertex was added, the smaller its rank. The ranks aren't
+    /// necessarily consecutive. Or `None` if the vertex is not in the graph.
+    fn insertion_rank(&self, node: &Self::Vertex) -> Option<usize>;
+
+    /// Returns an iterator over the neighbors of a given vertex.
+    /// Or `None` if the vertex does not exist in the graph.
+    fn neighbors_of(&self, node: &Self::Vertex) -> Option<Self::Neighbors<'_>>;
+
+    /// Returns an iterator over the `(neighbor, payload)` pairs of every
+    /// edge that starts at the given vertex.
+    /// Or `None` if the vertex does not exist in the graph.
+    fn edges_of(&self, node: &Self::Vertex) -> Option<Self::Edges<'_>>;
+
+    /// Returns an iterator over the vertices that have an edge to the given
+    /// vertex. Or `None` if the vertex does not exist in the graph.
+    fn predecessors_of(&self, node: &Self::Vertex) -> Option<Self::Predecessors<'_>>;
+
+    /// Returns the number of directed edges that start at the query vertex.
+    /// Or `None` if the query vertex does not exist in the graph.
+    fn out_degree(&self, node: &Self::Vertex) -> Option<usize> {
+        self.neighbors_of(node).map(|neighbors| neighbors.count())
+    }
+
+    /// Returns the number of directed edges that end at the query vertex.
+    /// Or `None` if the query vertex does not exist in the graph.
+    fn in_degree(&self, node: &Self::Vertex) -> Option<usize> {
+        self.predecessors_of(node).map(|predecessors| predecessors.count())
+    }
+
+    /// Returns `true` if there is a directed edge from `u` to `v`, `false` otherwise
+    fn are_neighbors(&self, u: &Self::Vertex, v: &Self::Vertex) -> bool {
+        self.neighbors_of(u).is_some_and(|mut neighbors| neighbors.any(|w| w == v))
+    }
+}
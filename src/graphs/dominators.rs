@@ -0,0 +1,280 @@
+use super::digraph::DiGraph;
+use super::directed_graph::DirectedGraph;
+use super::error::GraphError;
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+/// The dominator tree of a flow graph, along with the dominance frontiers.
+///
+/// A vertex `a` dominates `b` if every path from the root to `b` goes
+/// through `a`. Every vertex dominates itself. The immediate dominator of
+/// `b` is the closest of its other dominators, and linking every vertex to
+/// its immediate dominator gives the dominator tree.
+///
+/// Only the vertices that can be reached from the root are covered.
+#[derive(Debug, Clone)]
+pub struct Dominators<T: Clone + Eq + Hash> {
+    root: T,
+    /// Every reachable vertex, in reverse postorder
+    vertices: Vec<T>,
+    /// The immediate dominator of every reachable vertex but the root
+    idoms: HashMap<T, T>,
+    /// The dominance frontier of every reachable vertex
+    frontiers: HashMap<T, Vec<T>>
+}
+
+impl<T: Clone + Eq + Hash> Dominators<T> {
+    /// The vertex that all the paths start from, which dominates every
+    /// other reachable vertex
+    pub fn root(&self) -> &T {
+        &self.root
+    }
+
+    /// Every vertex that can be reached from the root, in reverse postorder
+    /// of a depth-first search. Every vertex comes after its dominators.
+    pub fn vertices(&self) -> &[T] {
+        &self.vertices
+    }
+
+    /// Returns `true` if `v` can be reached from the root
+    pub fn is_reachable(&self, v: &T) -> bool {
+        self.frontiers.contains_key(v)
+    }
+
+    /// Returns the immediate dominator of `v`, or `None` if `v` is the root
+    /// or can't be reached from it.
+    pub fn immediate_dominator(&self, v: &T) -> Option<&T> {
+        self.idoms.get(v)
+    }
+
+    /// Returns every dominator of `v`, starting with `v` itself and going up
+    /// the dominator tree to the root. Or `None` if `v` can't be reached
+    /// from the root.
+    pub fn dominators_of(&self, v: &T) -> Option<Vec<T>> {
+        if !self.is_reachable(v) {
+            return None;
+        }
+        let mut dominators = vec![v.clone()];
+        let mut current = v;
+        while let Some(idom) = self.idoms.get(current) {
+            dominators.push(idom.clone());
+            current = idom;
+        }
+        Some(dominators)
+    }
+
+    /// Returns `true` if `a` dominates `b`. Unreachable vertices don't
+    /// dominate, and aren't dominated by, anything.
+    /// This walks up the dominator tree from `b`, so it's proportional to
+    /// the depth of `b`.
+    pub fn dominates(&self, a: &T, b: &T) -> bool {
+        if !self.is_reachable(a) || !self.is_reachable(b) {
+            return false;
+        }
+        let mut current = b;
+        loop {
+            if current == a {
+                return true;
+            }
+            match self.idoms.get(current) {
+                Some(idom) => current = idom,
+                None => return false
+            }
+        }
+    }
+
+    /// Returns the dominance frontier of `v`: the vertices where `v` stops
+    /// dominating. Each one has a predecessor dominated by `v`, but isn't
+    /// strictly dominated by `v` itself. These are where SSA construction
+    /// places its phi functions.
+    ///
+    /// Or `None` if `v` can't be reached from the root.
+    pub fn dominance_frontier(&self, v: &T) -> Option<&[T]> {
+        self.frontiers.get(v).map(|frontier| frontier.as_slice())
+    }
+
+    /// Builds the dominator tree, with an edge from every vertex to each of
+    /// the vertices it immediately dominates.
+    pub fn dominator_tree(&self) -> DiGraph<T> {
+        let mut tree: DiGraph<T> = DiGraph::new();
+        // Safe to unwrap, the tree starts out empty
+        tree.add_vertex(self.root.clone()).unwrap();
+        for v in self.vertices.iter() {
+            if let Some(idom) = self.idoms.get(v) {
+                // Safe to unwrap, dominators come first in reverse postorder
+                tree.add_edge(idom, v).unwrap();
+            }
+        }
+        tree
+    }
+}
+
+/// Finds the dominators of every vertex that can be reached from `entry`,
+/// using the Cooper-Harvey-Kennedy algorithm. Runs in O(V+E) per pass, and
+/// takes very few passes on flow graphs that come out of structured code.
+///
+/// Returns `Err(GraphError::MissingVertex)` if `entry` is not in the graph.
+pub fn dominators<G, T>(g: &G, entry: &T) -> Result<Dominators<T>, GraphError>
+where
+    G: DirectedGraph<Vertex = T>,
+    T: Clone + Eq + Hash
+{
+    compute(g, entry, false)
+}
+
+/// Finds the post-dominators of every vertex that can reach `exit`. A
+/// vertex `a` post-dominates `b` if every path from `b` to the exit goes
+/// through `a`.
+///
+/// These are just the dominators of the reversed graph, with `exit` as the
+/// root, and are worked out by following the edges backwards rather than
+/// by building the reversed graph. Likewise, the dominance frontiers are
+/// post-dominance frontiers, which give the control dependencies. A flow
+/// graph with several exits needs a single virtual exit that they all
+/// lead to.
+///
+/// Returns `Err(GraphError::MissingVertex)` if `exit` is not in the graph.
+pub fn post_dominators<G, T>(g: &G, exit: &T) -> Result<Dominators<T>, GraphError>
+where
+    G: DirectedGraph<Vertex = T>,
+    T: Clone + Eq + Hash
+{
+    compute(g, exit, true)
+}
+
+/// The Cooper-Harvey-Kennedy algorithm, following the edges backwards if
+/// `reversed` is set.
+///
+/// The vertices are numbered in postorder, which makes the root the
+/// highest. Every vertex's immediate dominator is then found by walking up
+/// the current tree from each of its predecessors until the walks meet,
+/// going through the vertices in reverse postorder until nothing changes.
+fn compute<G, T>(g: &G, root: &T, reversed: bool) -> Result<Dominators<T>, GraphError>
+where
+    G: DirectedGraph<Vertex = T>,
+    T: Clone + Eq + Hash
+{
+    if !g.contains(root) {
+        return Err(GraphError::MissingVertex);
+    }
+    // Safe to unwrap, these are only ever called on vertices of the graph
+    let successors = |v: &T| -> Vec<&T> {
+        if reversed {
+            g.predecessors_of(v).unwrap().collect()
+        } else {
+            g.neighbors_of(v).unwrap().collect()
+        }
+    };
+    let predecessors = |v: &T| -> Vec<&T> {
+        if reversed {
+            g.neighbors_of(v).unwrap().collect()
+        } else {
+            g.predecessors_of(v).unwrap().collect()
+        }
+    };
+
+    // Number the reachable vertices in postorder, without recursion
+    let mut postorder: Vec<&T> = Vec::new();
+    let mut visited: HashSet<&T> = HashSet::new();
+    visited.insert(root);
+    let mut stack = vec![(root, successors(root).into_iter())];
+    while let Some((v, next)) = stack.last_mut() {
+        let v = *v;
+        match next.next() {
+            Some(w) => {
+                if visited.insert(w) {
+                    stack.push((w, successors(w).into_iter()));
+                }
+            }
+            None => {
+                postorder.push(v);
+                stack.pop();
+            }
+        }
+    }
+    let number: HashMap<&T, usize> = postorder.iter()
+        .enumerate()
+        .map(|(i, v)| (*v, i))
+        .collect();
+    let n = postorder.len();
+    let top = n - 1;
+    let preds: Vec<Vec<usize>> = postorder.iter()
+        .map(|v| predecessors(v).into_iter().filter_map(|p| number.get(p).copied()).collect())
+        .collect();
+
+    let mut idoms: Vec<Option<usize>> = vec![None; n];
+    idoms[top] = Some(top);
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for b in (0..top).rev() {
+            let mut new_idom: Option<usize> = None;
+            for &p in preds[b].iter() {
+                if idoms[p].is_none() {
+                    continue;
+                }
+                new_idom = Some(match new_idom {
+                    None => p,
+                    Some(other) => intersect(&idoms, p, other)
+                });
+            }
+            if idoms[b] != new_idom {
+                idoms[b] = new_idom;
+                changed = true;
+            }
+        }
+    }
+
+    // Walk up from the predecessors of every join point until reaching its
+    // immediate dominator, adding it to the frontier of everything on the
+    // way. The root is treated as if it also had an edge coming in from
+    // outside, so it's a join point as soon as anything leads back to it.
+    let mut frontiers: Vec<Vec<usize>> = vec![Vec::new(); n];
+    for b in (0..n).rev() {
+        let (stop, joins) = if b == top {
+            (None, !preds[b].is_empty())
+        } else {
+            (idoms[b], preds[b].len() >= 2)
+        };
+        if !joins {
+            continue;
+        }
+        for &p in preds[b].iter() {
+            let mut runner = Some(p);
+            while runner != stop {
+                // Safe to unwrap, the loop stops at the root at the latest
+                let r = runner.unwrap();
+                if frontiers[r].last() != Some(&b) {
+                    frontiers[r].push(b);
+                }
+                runner = if r == top { None } else { idoms[r] };
+            }
+        }
+    }
+
+    let vertices: Vec<T> = postorder.iter().rev().map(|v| (*v).clone()).collect();
+    let idoms: HashMap<T, T> = (0..top)
+        .map(|v| (postorder[v].clone(), postorder[idoms[v].unwrap()].clone()))
+        .collect();
+    let frontiers: HashMap<T, Vec<T>> = frontiers.into_iter()
+        .enumerate()
+        .map(|(v, frontier)| (postorder[v].clone(), frontier.into_iter().map(|w| postorder[w].clone()).collect()))
+        .collect();
+
+    Ok(Dominators { root: root.clone(), vertices, idoms, frontiers })
+}
+
+/// Finds the closest common dominator of `a` and `b`, by repeatedly moving
+/// whichever of them has the lower postorder number up the tree
+fn intersect(idoms: &[Option<usize>], mut a: usize, mut b: usize) -> usize {
+    while a != b {
+        while a < b {
+            // Safe to unwrap, only processed vertices are ever passed in
+            a = idoms[a].unwrap();
+        }
+        while b < a {
+            b = idoms[b].unwrap();
+        }
+    }
+    a
+}
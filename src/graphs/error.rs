@@ -0,0 +1,49 @@
+use std::error::Error;
+use std::fmt;
+
+/// Everything that can go wrong when building or querying one of the
+/// graphs in this module.
+///
+/// The variants intentionally don't carry the offending vertex around,
+/// that way the error type doesn't need to be generic over `T` and can
+/// be used with `?` alongside any other `std::error::Error`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GraphError {
+    /// Attempted to insert a vertex that is already in the graph
+    DuplicateVertex,
+    /// The start vertex of an edge or path is not in the graph
+    MissingSourceVertex,
+    /// The end vertex of an edge or path is not in the graph
+    MissingTargetVertex,
+    /// A vertex that an operation was asked to start from is not in the graph
+    MissingVertex,
+    /// The operation requires an acyclic graph, but a cycle was found
+    CycleDetected,
+    /// The operation requires non-negative edge weights, but a negative one was found
+    NegativeWeight,
+    /// The graph has a cycle whose total weight is negative
+    NegativeCycle,
+    /// The operation needs two different vertices, but was given the same one twice
+    SourceIsSink,
+    /// The operation requires a bipartite graph, but an edge joins two vertices on the same side
+    NotBipartite,
+}
+
+impl fmt::Display for GraphError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let msg = match self {
+            GraphError::DuplicateVertex => "Attempted to insert duplicate vertex",
+            GraphError::MissingSourceVertex => "Start vertex of edge or path not present",
+            GraphError::MissingTargetVertex => "End vertex of edge or path not present",
+            GraphError::MissingVertex => "Vertex not present in the graph",
+            GraphError::CycleDetected => "Graph contains a cycle",
+            GraphError::NegativeWeight => "Graph contains a negative edge weight",
+            GraphError::NegativeCycle => "Graph contains a negative cycle",
+            GraphError::SourceIsSink => "Source and sink are the same vertex",
+            GraphError::NotBipartite => "Graph is not bipartite",
+        };
+        write!(f, "{}", msg)
+    }
+}
+
+impl Error for GraphError {}
@@ -0,0 +1,197 @@
+use super::digraph::{DiGraph, Neighbors};
+use super::error::GraphError;
+use super::traversal::{Bfs, Dfs};
+use std::collections::hash_map::Keys;
+use std::collections::HashSet;
+use std::hash::Hash;
+use std::slice::Iter;
+
+/// Undirected graph, built on top of a DiGraph.
+///
+/// Every edge is stored as a pair of directed edges, one going each way,
+/// and the two are always added and removed together. A self-loop is only
+/// stored once.
+///
+/// T and E mean the same thing as they do for DiGraph. Since each edge is
+/// stored twice, so is its payload, which is why `add_edge_with` needs
+/// `E: Clone`.
+pub struct Graph<T: Clone + Eq + Hash, E = ()> {
+    inner: DiGraph<T, E>,
+    num_edges: usize
+}
+
+impl<T: Clone + Eq + Hash, E> Default for Graph<T, E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone + Eq + Hash> Graph<T> {
+    /// Adds an undirected edge between `u` and `v`. Returns `Ok(())` if the
+    /// operation was successful, but `Err(GraphError::MissingSourceVertex)`
+    /// if `u` does not exist in the graph.
+    pub fn add_edge(&mut self, u: &T, v: &T) -> Result<(), GraphError> {
+        self.add_edge_with(u, v, ())
+    }
+}
+
+impl<T: Clone + Eq + Hash, E> Graph<T, E> {
+    /// Constructs a new, empty Graph
+    pub fn new() -> Graph<T, E> {
+        Graph {
+            inner: DiGraph::new(),
+            num_edges: 0
+        }
+    }
+
+    /// Returns a view of this graph as a DiGraph with a directed edge going
+    /// each way for every undirected edge. Any algorithm that works on a
+    /// DiGraph can be run on the undirected graph through this.
+    pub fn as_digraph(&self) -> &DiGraph<T, E> {
+        &self.inner
+    }
+
+    /// Returns the number of vertices present in the graph
+    pub fn num_vertices(&self) -> usize {
+        self.inner.num_vertices()
+    }
+
+    /// Returns the number of (undirected) edges present in the graph
+    /// This is an `O(1)` operation
+    pub fn num_edges(&self) -> usize {
+        self.num_edges
+    }
+
+    /// Returns an iterator over every vertex in the graph, in no particular order.
+    pub fn vertices(&self) -> Keys<'_, T, Vec<(T, E)>> {
+        self.inner.vertices()
+    }
+
+    /// Returns every vertex in the graph, ordered by when they were added.
+    /// See `DiGraph::vertices_in_insertion_order`.
+    pub fn vertices_in_insertion_order(&self) -> Vec<&T> {
+        self.inner.vertices_in_insertion_order()
+    }
+
+    /// Adds a new, unconnected, vertex to the graph.
+    /// Returns `Err(GraphError::DuplicateVertex)` if the vertex already exists.
+    pub fn add_vertex(&mut self, node: T) -> Result<(), GraphError> {
+        self.inner.add_vertex(node)
+    }
+
+    /// Returns `true` if the query vertex exists in the graph, `false` otherwise
+    pub fn contains(&self, node: &T) -> bool {
+        self.inner.contains(node)
+    }
+
+    /// Returns `true` if there is an edge between `u` and `v`, `false` otherwise.
+    /// The order of `u` and `v` doesn't matter.
+    pub fn are_neighbors(&self, u: &T, v: &T) -> bool {
+        self.inner.are_neighbors(u, v)
+    }
+
+    /// Returns an iterator over the neighbors of a given vertex.
+    /// Or `None` if the vertex does not exist in the graph.
+    pub fn neighbors_of(&self, node: &T) -> Option<Neighbors<'_, T, E>> {
+        self.inner.neighbors_of(node)
+    }
+
+    /// Returns an iterator over the `(neighbor, payload)` pairs of every
+    /// edge touching the given vertex.
+    /// Or `None` if the vertex does not exist in the graph.
+    pub fn edges_of(&self, node: &T) -> Option<Iter<'_, (T, E)>> {
+        self.inner.edges_of(node)
+    }
+
+    /// Returns the payload of the edge between `u` and `v`, or `None`
+    /// if there is no such edge.
+    pub fn edge_weight(&self, u: &T, v: &T) -> Option<&E> {
+        self.inner.edge_weight(u, v)
+    }
+
+    /// Returns the number of edges touching the query vertex, where a
+    /// self-loop counts twice (it touches the vertex at both ends).
+    /// Or `None` if the query vertex does not exist in the graph.
+    pub fn degree(&self, node: &T) -> Option<usize> {
+        let edges = self.inner.edges_of(node)?;
+        let self_loops = edges.clone().filter(|(v, _payload)| v == node).count();
+        Some(edges.len() + self_loops)
+    }
+
+    /// Adds an undirected edge between `u` and `v` carrying `payload`.
+    /// Returns `Ok(())` if the operation was successful, but
+    /// `Err(GraphError::MissingSourceVertex)` if `u` does not exist in the graph.
+    pub fn add_edge_with(&mut self, u: &T, v: &T, payload: E) -> Result<(), GraphError> where E: Clone {
+        if u != v {
+            self.inner.add_edge_with(u, v, payload.clone())?;
+            // `u` definitely exists now, so this can't fail
+            self.inner.add_edge_with(v, u, payload).unwrap();
+        } else {
+            self.inner.add_edge_with(u, v, payload)?;
+        }
+        self.num_edges += 1;
+        Ok(())
+    }
+
+    /// Removes the edge between `u` and `v`, if it exists.
+    /// If the edge does not exist, this operation is idempotent.
+    /// If there are several edges between `u` and `v`, only the one that
+    /// was added first is removed.
+    pub fn remove_edge(&mut self, u: &T, v: &T) {
+        if self.inner.are_neighbors(u, v) {
+            self.inner.remove_edge(u, v);
+            if u != v {
+                self.inner.remove_edge(v, u);
+            }
+            self.num_edges -= 1;
+        }
+    }
+
+    /// Removes the target vertex and every edge touching it.
+    pub fn remove_vertex(&mut self, target: &T) {
+        // Every entry in the target's own edge list is a distinct edge,
+        // self-loops included, since those are only stored once.
+        if let Some(degree) = self.inner.out_degree(target) {
+            self.num_edges -= degree;
+            self.inner.remove_vertex(target);
+        }
+    }
+
+    /// Returns the connected components of the graph: groups of vertices
+    /// where there is a path between every pair of vertices in the group,
+    /// and no path to any vertex outside of it.
+    ///
+    /// The components are ordered by when their first vertex was added to
+    /// the graph, and each one is in breadth-first order starting from that
+    /// vertex. Runs in O(V+E).
+    pub fn connected_components(&self) -> Vec<Vec<T>> {
+        let mut components: Vec<Vec<T>> = Vec::new();
+        let mut seen: HashSet<&T> = HashSet::new();
+
+        for root in self.inner.vertices_in_insertion_order() {
+            if seen.contains(root) {
+                continue;
+            }
+            // Safe to unwrap, root came straight out of the graph
+            let component: Vec<T> = Bfs::new(&self.inner, root).unwrap()
+                .inspect(|v| {
+                    seen.insert(v);
+                })
+                .cloned()
+                .collect();
+            components.push(component);
+        }
+
+        components
+    }
+
+    /// Starts a breadth-first traversal at `start`. See `traversal::Bfs`.
+    pub fn bfs(&self, start: &T) -> Result<Bfs<'_, DiGraph<T, E>>, GraphError> {
+        Bfs::new(&self.inner, start)
+    }
+
+    /// Starts a depth-first traversal at `start`. See `traversal::Dfs`.
+    pub fn dfs(&self, start: &T) -> Result<Dfs<'_, DiGraph<T, E>>, GraphError> {
+        Dfs::new(&self.inner, start)
+    }
+}
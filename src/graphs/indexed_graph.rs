@@ -0,0 +1,481 @@
+use std::convert::TryFrom;
+use std::hash::Hash;
+use std::iter::Map;
+use std::ops::Range;
+use std::slice::Iter;
+use super::digraph::DiGraph;
+use super::directed_graph::DirectedGraph;
+use super::error::GraphError;
+use super::vertex_lookup::VertexLookup;
+
+/// Handle to a vertex of an IndexedGraph. It's just a small number, so it's
+/// cheap to copy, hash and compare no matter what the vertices are.
+///
+/// A handle only means something to the graph that handed it out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NodeId(u32);
+
+impl NodeId {
+    /// Returns the position of the vertex in the graph. The vertices are
+    /// numbered `0..num_vertices()` in the order they were added.
+    pub fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+/// Handle to an edge of an IndexedGraph. Parallel edges each get their own.
+///
+/// A handle only means something to the graph that handed it out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct EdgeId(u32);
+
+impl EdgeId {
+    /// Returns the position of the edge in the graph. The edges are
+    /// numbered `0..num_edges()` in the order they were added.
+    pub fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Edge<E> {
+    source: NodeId,
+    target: NodeId,
+    payload: E
+}
+
+/// A directed graph that stores every vertex exactly once, and refers to it
+/// everywhere else by its `NodeId`. Unlike a DiGraph, adding an edge never
+/// clones a vertex, which matters when the vertices are something like
+/// `String`s.
+///
+/// Vertices can be looked up both ways: `node_id` goes from a vertex to its
+/// handle, and `vertex` goes back. Edges get an `EdgeId` of their own, so
+/// parallel edges can be told apart.
+///
+/// The methods that take a vertex (`neighbors_of`, `out_degree`,
+/// `edge_weight`, ...) work just like the ones on a DiGraph. The ones that
+/// take handles (`successors`, `outgoing_edges`, `find_edge`, ...) skip
+/// looking the vertex up, and hand back handles instead of vertices.
+///
+/// Vertices and edges can't be removed, which is what keeps every handle
+/// valid for as long as the graph is around.
+///
+/// Implements `DirectedGraph` with the vertices themselves (not the
+/// handles), so the graph algorithms work on it the same way they do on a
+/// DiGraph.
+#[derive(Debug, Clone)]
+pub struct IndexedGraph<T: Clone + Eq + Hash, E = ()> {
+    vertices: Vec<T>,
+    // Finds the handle of a vertex by its hash, so it isn't stored twice
+    ids: VertexLookup,
+    edges: Vec<Edge<E>>,
+    // The edges that start and end at each vertex, indexed by NodeId
+    outgoing: Vec<Vec<EdgeId>>,
+    incoming: Vec<Vec<EdgeId>>
+}
+
+impl<T: Clone + Eq + Hash, E> Default for IndexedGraph<T, E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone + Eq + Hash> IndexedGraph<T> {
+    /// Adds a directed edge between `u` and `v`, and returns its handle.
+    /// See `add_edge_with`.
+    pub fn add_edge(&mut self, u: NodeId, v: NodeId) -> Result<EdgeId, GraphError> {
+        self.add_edge_with(u, v, ())
+    }
+}
+
+impl<T: Clone + Eq + Hash, E> IndexedGraph<T, E> {
+    /// Constructs a new, empty IndexedGraph
+    pub fn new() -> IndexedGraph<T, E> {
+        IndexedGraph {
+            vertices: Vec::new(),
+            ids: VertexLookup::default(),
+            edges: Vec::new(),
+            outgoing: Vec::new(),
+            incoming: Vec::new()
+        }
+    }
+
+    /// Returns the number of vertices present in the graph
+    pub fn num_vertices(&self) -> usize {
+        self.vertices.len()
+    }
+
+    /// Returns the number of edges present in the graph
+    pub fn num_edges(&self) -> usize {
+        self.edges.len()
+    }
+
+    /// Adds a vertex to the graph and returns its handle.
+    /// Returns `Err(GraphError::DuplicateVertex)` if it is already in the graph.
+    ///
+    /// Panics if the graph already has `u32::MAX` vertices.
+    pub fn add_vertex(&mut self, node: T) -> Result<NodeId, GraphError> {
+        if self.contains(&node) {
+            return Err(GraphError::DuplicateVertex);
+        }
+        Ok(self.insert_vertex(node))
+    }
+
+    /// Returns the handle of the vertex, adding it to the graph first if it
+    /// isn't there yet.
+    ///
+    /// Panics if the vertex is new and the graph already has `u32::MAX` vertices.
+    pub fn intern(&mut self, node: T) -> NodeId {
+        match self.node_id(&node) {
+            Some(id) => id,
+            None => self.insert_vertex(node)
+        }
+    }
+
+    fn insert_vertex(&mut self, node: T) -> NodeId {
+        let id = NodeId(u32::try_from(self.vertices.len()).expect("Too many vertices for u32 ids"));
+        self.ids.insert(&node, id.0);
+        self.vertices.push(node);
+        self.outgoing.push(Vec::new());
+        self.incoming.push(Vec::new());
+        id
+    }
+
+    /// Returns the handle of the vertex, or `None` if it is not in the graph
+    pub fn node_id(&self, node: &T) -> Option<NodeId> {
+        self.ids.get(&self.vertices, node).map(NodeId)
+    }
+
+    /// Returns the vertex behind the handle, or `None` if there is no such
+    /// vertex in the graph
+    pub fn vertex(&self, id: NodeId) -> Option<&T> {
+        self.vertices.get(id.index())
+    }
+
+    /// Returns `true` if the query vertex exists in the graph, `false` otherwise
+    pub fn contains(&self, node: &T) -> bool {
+        self.node_id(node).is_some()
+    }
+
+    /// Returns the graph's own copy of the query vertex, which lives as long
+    /// as the graph is borrowed. Or `None` if the vertex is not in the graph.
+    pub fn find_vertex(&self, node: &T) -> Option<&T> {
+        self.node_id(node).map(|id| &self.vertices[id.index()])
+    }
+
+    /// Returns an iterator over every vertex in the graph, in the order they
+    /// were added
+    pub fn vertices(&self) -> Iter<'_, T> {
+        self.vertices.iter()
+    }
+
+    /// Returns an iterator over the handles of every vertex in the graph,
+    /// in the order they were added
+    pub fn node_ids(&self) -> Map<Range<u32>, fn(u32) -> NodeId> {
+        // Safe to cast, insert_vertex makes sure the count fits
+        (0..self.vertices.len() as u32).map(NodeId as fn(u32) -> NodeId)
+    }
+
+    /// Returns an iterator over the handles of every edge in the graph,
+    /// in the order they were added
+    pub fn edge_ids(&self) -> Map<Range<u32>, fn(u32) -> EdgeId> {
+        // Safe to cast, add_edge_with makes sure the count fits
+        (0..self.edges.len() as u32).map(EdgeId as fn(u32) -> EdgeId)
+    }
+
+    /// Adds a directed edge between `u` and `v` carrying `payload`, and
+    /// returns its handle. Returns `Err(GraphError::MissingSourceVertex)` or
+    /// `Err(GraphError::MissingTargetVertex)` if either handle doesn't
+    /// belong to a vertex of the graph.
+    ///
+    /// Panics if the graph already has `u32::MAX` edges.
+    pub fn add_edge_with(&mut self, u: NodeId, v: NodeId, payload: E) -> Result<EdgeId, GraphError> {
+        if u.index() >= self.vertices.len() {
+            return Err(GraphError::MissingSourceVertex);
+        }
+        if v.index() >= self.vertices.len() {
+            return Err(GraphError::MissingTargetVertex);
+        }
+        let id = EdgeId(u32::try_from(self.edges.len()).expect("Too many edges for u32 ids"));
+        self.edges.push(Edge { source: u, target: v, payload });
+        self.outgoing[u.index()].push(id);
+        self.incoming[v.index()].push(id);
+        Ok(id)
+    }
+
+    /// Returns the `(source, target)` handles of the edge, or `None` if
+    /// there is no such edge in the graph
+    pub fn edge_endpoints(&self, id: EdgeId) -> Option<(NodeId, NodeId)> {
+        self.edges.get(id.index()).map(|edge| (edge.source, edge.target))
+    }
+
+    /// Returns the payload of the edge, or `None` if there is no such edge
+    /// in the graph
+    pub fn edge_payload(&self, id: EdgeId) -> Option<&E> {
+        self.edges.get(id.index()).map(|edge| &edge.payload)
+    }
+
+    /// Returns a mutable reference to the payload of the edge, or `None` if
+    /// there is no such edge in the graph
+    pub fn edge_payload_mut(&mut self, id: EdgeId) -> Option<&mut E> {
+        self.edges.get_mut(id.index()).map(|edge| &mut edge.payload)
+    }
+
+    /// Returns the handles of the edges that start at the vertex, in the
+    /// order they were added. Or `None` if there is no such vertex.
+    pub fn outgoing_edges(&self, id: NodeId) -> Option<&[EdgeId]> {
+        self.outgoing.get(id.index()).map(|edges| edges.as_slice())
+    }
+
+    /// Returns the handles of the edges that end at the vertex, in the
+    /// order they were added. Or `None` if there is no such vertex.
+    pub fn incoming_edges(&self, id: NodeId) -> Option<&[EdgeId]> {
+        self.incoming.get(id.index()).map(|edges| edges.as_slice())
+    }
+
+    /// Returns an iterator over the handles of the vertices that the vertex
+    /// has an edge to. Or `None` if there is no such vertex.
+    pub fn successors(&self, id: NodeId) -> Option<Successors<'_, E>> {
+        let edges = self.outgoing.get(id.index())?;
+        Some(Successors { edges: &self.edges, ids: edges.iter() })
+    }
+
+    /// Returns an iterator over the handles of the vertices that have an
+    /// edge to the vertex. Or `None` if there is no such vertex.
+    pub fn predecessors(&self, id: NodeId) -> Option<Predecessors<'_, E>> {
+        let edges = self.incoming.get(id.index())?;
+        Some(Predecessors { edges: &self.edges, ids: edges.iter() })
+    }
+
+    /// Returns the handle of an edge from `u` to `v`, or `None` if there is
+    /// no such edge. If there are several, this is the one added first.
+    pub fn find_edge(&self, u: NodeId, v: NodeId) -> Option<EdgeId> {
+        self.outgoing.get(u.index())?
+            .iter()
+            .find(|e| self.edges[e.index()].target == v)
+            .copied()
+    }
+
+    /// Returns an iterator over the neighbors of a given vertex.
+    /// Or `None` if the vertex does not exist in the graph.
+    pub fn neighbors_of(&self, node: &T) -> Option<Neighbors<'_, T, E>> {
+        let id = self.node_id(node)?;
+        Some(Neighbors { graph: self, ids: self.outgoing[id.index()].iter(), end: |edge| edge.target })
+    }
+
+    /// Returns an iterator over the vertices that have an edge to the given
+    /// vertex. Or `None` if the vertex does not exist in the graph.
+    pub fn predecessors_of(&self, node: &T) -> Option<Neighbors<'_, T, E>> {
+        let id = self.node_id(node)?;
+        Some(Neighbors { graph: self, ids: self.incoming[id.index()].iter(), end: |edge| edge.source })
+    }
+
+    /// Returns an iterator over the `(neighbor, payload)` pairs of every
+    /// edge that starts at the given vertex.
+    /// Or `None` if the vertex does not exist in the graph.
+    pub fn edges_of(&self, node: &T) -> Option<Edges<'_, T, E>> {
+        let id = self.node_id(node)?;
+        Some(Edges { graph: self, ids: self.outgoing[id.index()].iter() })
+    }
+
+    /// Returns `true` if there is a directed edge from `u` to `v`, `false` otherwise
+    pub fn are_neighbors(&self, u: &T, v: &T) -> bool {
+        match (self.node_id(u), self.node_id(v)) {
+            (Some(u), Some(v)) => self.find_edge(u, v).is_some(),
+            _ => false
+        }
+    }
+
+    /// Returns the payload of the directed edge from `u` to `v`, or `None`
+    /// if there is no such edge. If there are several edges from `u` to `v`,
+    /// this is the payload of the one that was added first.
+    pub fn edge_weight(&self, u: &T, v: &T) -> Option<&E> {
+        let id = self.find_edge(self.node_id(u)?, self.node_id(v)?)?;
+        self.edge_payload(id)
+    }
+
+    /// Returns the number of directed edges that start at the query vertex.
+    /// Or `None` if the query vertex does not exist in the graph.
+    /// Given a handle, `outgoing_edges` has the same count without the lookup.
+    pub fn out_degree(&self, node: &T) -> Option<usize> {
+        self.node_id(node).map(|id| self.outgoing[id.index()].len())
+    }
+
+    /// Returns the number of directed edges that end at the query vertex.
+    /// Or `None` if the query vertex does not exist in the graph.
+    /// Given a handle, `incoming_edges` has the same count without the lookup.
+    pub fn in_degree(&self, node: &T) -> Option<usize> {
+        self.node_id(node).map(|id| self.incoming[id.index()].len())
+    }
+}
+
+impl<T: Clone + Eq + Hash, E: Clone> From<&DiGraph<T, E>> for IndexedGraph<T, E> {
+    /// Copies a DiGraph. The vertices get their handles in insertion order,
+    /// and the edges out of each vertex keep the order they were added in.
+    fn from(g: &DiGraph<T, E>) -> Self {
+        let mut indexed = IndexedGraph::new();
+        let vertices = g.vertices_in_insertion_order();
+        for v in vertices.iter() {
+            // Safe to unwrap, every vertex is unique
+            indexed.add_vertex((*v).clone()).unwrap();
+        }
+        for u in vertices.iter() {
+            // Safe to unwrap, every vertex was added above
+            let u_id = indexed.node_id(u).unwrap();
+            // Safe to unwrap, u came from the graph
+            for (v, payload) in g.edges_of(u).unwrap() {
+                // Safe to unwrap, both ends were added above
+                let v_id = indexed.node_id(v).unwrap();
+                indexed.add_edge_with(u_id, v_id, payload.clone()).unwrap();
+            }
+        }
+        indexed
+    }
+}
+
+impl<T: Clone + Eq + Hash, E> DirectedGraph for IndexedGraph<T, E> {
+    type Vertex = T;
+    type Edge = E;
+    type Vertices<'a> = Iter<'a, T> where Self: 'a;
+    type Neighbors<'a> = Neighbors<'a, T, E> where Self: 'a;
+    type Predecessors<'a> = Neighbors<'a, T, E> where Self: 'a;
+    type Edges<'a> = Edges<'a, T, E> where Self: 'a;
+
+    fn num_vertices(&self) -> usize {
+        self.num_vertices()
+    }
+
+    fn num_edges(&self) -> usize {
+        self.num_edges()
+    }
+
+    fn contains(&self, node: &T) -> bool {
+        self.contains(node)
+    }
+
+    fn find_vertex(&self, node: &T) -> Option<&T> {
+        self.find_vertex(node)
+    }
+
+    fn vertices(&self) -> Self::Vertices<'_> {
+        self.vertices()
+    }
+
+    fn vertices_in_insertion_order(&self) -> Vec<&T> {
+        // Already in order, no sorting needed
+        self.vertices.iter().collect()
+    }
+
+    fn insertion_rank(&self, node: &T) -> Option<usize> {
+        self.node_id(node).map(|id| id.index())
+    }
+
+    fn neighbors_of(&self, node: &T) -> Option<Self::Neighbors<'_>> {
+        self.neighbors_of(node)
+    }
+
+    fn edges_of(&self, node: &T) -> Option<Self::Edges<'_>> {
+        self.edges_of(node)
+    }
+
+    fn predecessors_of(&self, node: &T) -> Option<Self::Predecessors<'_>> {
+        self.predecessors_of(node)
+    }
+
+    fn out_degree(&self, node: &T) -> Option<usize> {
+        self.out_degree(node)
+    }
+
+    fn in_degree(&self, node: &T) -> Option<usize> {
+        self.in_degree(node)
+    }
+
+    fn are_neighbors(&self, u: &T, v: &T) -> bool {
+        self.are_neighbors(u, v)
+    }
+}
+
+/// Iterator over the handles of the vertices a vertex has edges to.
+/// See `IndexedGraph::successors`.
+#[derive(Clone)]
+pub struct Successors<'a, E> {
+    edges: &'a [Edge<E>],
+    ids: Iter<'a, EdgeId>
+}
+
+impl<E> Iterator for Successors<'_, E> {
+    type Item = NodeId;
+
+    fn next(&mut self) -> Option<NodeId> {
+        self.ids.next().map(|e| self.edges[e.index()].target)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.ids.size_hint()
+    }
+}
+
+/// Iterator over the handles of the vertices that have edges to a vertex.
+/// See `IndexedGraph::predecessors`.
+#[derive(Clone)]
+pub struct Predecessors<'a, E> {
+    edges: &'a [Edge<E>],
+    ids: Iter<'a, EdgeId>
+}
+
+impl<E> Iterator for Predecessors<'_, E> {
+    type Item = NodeId;
+
+    fn next(&mut self) -> Option<NodeId> {
+        self.ids.next().map(|e| self.edges[e.index()].source)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.ids.size_hint()
+    }
+}
+
+/// Iterator over the neighbors (or predecessors) of a vertex, which turns
+/// their handles back into vertices. See `IndexedGraph::neighbors_of`.
+#[derive(Clone)]
+pub struct Neighbors<'a, T: Clone + Eq + Hash, E> {
+    graph: &'a IndexedGraph<T, E>,
+    ids: Iter<'a, EdgeId>,
+    // Which end of each edge to hand out
+    end: fn(&Edge<E>) -> NodeId
+}
+
+impl<'a, T: Clone + Eq + Hash, E> Iterator for Neighbors<'a, T, E> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        let edge = &self.graph.edges[self.ids.next()?.index()];
+        Some(&self.graph.vertices[(self.end)(edge).index()])
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.ids.size_hint()
+    }
+}
+
+/// Iterator over the `(neighbor, payload)` pairs of the edges out of a
+/// vertex. See `IndexedGraph::edges_of`.
+#[derive(Clone)]
+pub struct Edges<'a, T: Clone + Eq + Hash, E> {
+    graph: &'a IndexedGraph<T, E>,
+    ids: Iter<'a, EdgeId>
+}
+
+impl<'a, T: Clone + Eq + Hash, E> Iterator for Edges<'a, T, E> {
+    type Item = (&'a T, &'a E);
+
+    fn next(&mut self) -> Option<(&'a T, &'a E)> {
+        let edge = &self.graph.edges[self.ids.next()?.index()];
+        Some((&self.graph.vertices[edge.target.index()], &edge.payload))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.ids.size_hint()
+    }
+}
@@ -0,0 +1,244 @@
+use super::directed_graph::DirectedGraph;
+use super::error::GraphError;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+/// A matching: a set of edges where no two edges share a vertex.
+///
+/// Each pair is `(left, right)`, meaning the first vertex is the one on
+/// the left side of the bipartite graph.
+#[derive(Debug, Clone)]
+pub struct Matching<T: Clone + Eq + Hash> {
+    pairs: Vec<(T, T)>,
+    mates: HashMap<T, T>
+}
+
+impl<T: Clone + Eq + Hash> Matching<T> {
+    /// Every matched pair, ordered by when the left vertex was added to the graph
+    pub fn pairs(&self) -> &[(T, T)] {
+        &self.pairs
+    }
+
+    /// Returns the number of matched pairs
+    pub fn len(&self) -> usize {
+        self.pairs.len()
+    }
+
+    /// Returns `true` if nothing was matched at all
+    pub fn is_empty(&self) -> bool {
+        self.pairs.is_empty()
+    }
+
+    /// Returns the vertex matched with `v`, from either side, or `None`
+    /// if `v` is unmatched (or not in the graph).
+    pub fn mate_of(&self, v: &T) -> Option<&T> {
+        self.mates.get(v)
+    }
+}
+
+/// Splits the vertices of the graph into two sides, so that every edge goes
+/// between the two sides. The direction of the edges is ignored, so this
+/// works on an undirected `Graph` through `Graph::as_digraph` too.
+///
+/// For every connected component, the vertex added first goes on the left.
+/// Both sides are in insertion order. Returns `Err(GraphError::NotBipartite)`
+/// if there's no way to split the graph like that, which is the case
+/// exactly when it has a cycle of odd length (self-loops included).
+pub fn bipartition<G, T>(g: &G) -> Result<(Vec<T>, Vec<T>), GraphError>
+where
+    G: DirectedGraph<Vertex = T>,
+    T: Clone + Eq + Hash
+{
+    let vertices = g.vertices_in_insertion_order();
+    let mut on_left: HashMap<&T, bool> = HashMap::new();
+    let mut queue: VecDeque<&T> = VecDeque::new();
+
+    for root in vertices.iter() {
+        if on_left.contains_key(root) {
+            continue;
+        }
+        on_left.insert(root, true);
+        queue.push_back(root);
+        while let Some(u) = queue.pop_front() {
+            let side = on_left[u];
+            // Safe to unwrap, u is always a vertex of the graph
+            for v in g.neighbors_of(u).unwrap().chain(g.predecessors_of(u).unwrap()) {
+                match on_left.get(v) {
+                    Some(other) if *other == side => return Err(GraphError::NotBipartite),
+                    Some(_) => (),
+                    None => {
+                        on_left.insert(v, !side);
+                        queue.push_back(v);
+                    }
+                }
+            }
+        }
+    }
+
+    let (left, right): (Vec<&T>, Vec<&T>) = vertices.into_iter().partition(|v| on_left[v]);
+    Ok((left.into_iter().cloned().collect(), right.into_iter().cloned().collect()))
+}
+
+/// The Hopcroft-Karp algorithm. Finds a maximum matching of a bipartite
+/// graph, meaning one with as many pairs as possible. Runs in O(E sqrt(V)).
+///
+/// The two sides are worked out by `bipartition`, which also means the
+/// direction of the edges is ignored. Fails with
+/// `Err(GraphError::NotBipartite)` if the graph isn't bipartite.
+/// See `hopcroft_karp_with` to pick the sides yourself.
+pub fn hopcroft_karp<G, T>(g: &G) -> Result<Matching<T>, GraphError>
+where
+    G: DirectedGraph<Vertex = T>,
+    T: Clone + Eq + Hash
+{
+    let (left, _right) = bipartition(g)?;
+    let left: HashSet<T> = left.into_iter().collect();
+    hopcroft_karp_with(g, |v| left.contains(v))
+}
+
+/// The Hopcroft-Karp algorithm, where `is_left` says which side each vertex
+/// is on. Handy when the sides mean something, like workers on the left and
+/// jobs on the right, since every pair is then `(worker, job)`.
+///
+/// The direction of the edges is ignored. Returns
+/// `Err(GraphError::NotBipartite)` if any edge joins two vertices on the
+/// same side.
+pub fn hopcroft_karp_with<G, T, F>(g: &G, is_left: F) -> Result<Matching<T>, GraphError>
+where
+    G: DirectedGraph<Vertex = T>,
+    T: Clone + Eq + Hash,
+    F: Fn(&T) -> bool
+{
+    let (left, right): (Vec<&T>, Vec<&T>) = g.vertices_in_insertion_order()
+        .into_iter()
+        .partition(|v| is_left(v));
+    let right_index: HashMap<&T, usize> = right.iter()
+        .enumerate()
+        .map(|(i, v)| (*v, i))
+        .collect();
+
+    let mut adjacency: Vec<Vec<usize>> = Vec::with_capacity(left.len());
+    for u in left.iter() {
+        // Safe to unwrap, u came straight out of the graph
+        let mut neighbors = Vec::new();
+        for v in g.neighbors_of(u).unwrap().chain(g.predecessors_of(u).unwrap()) {
+            match right_index.get(v) {
+                Some(j) => neighbors.push(*j),
+                None => return Err(GraphError::NotBipartite)
+            }
+        }
+        adjacency.push(neighbors);
+    }
+    // Edges between two right vertices wouldn't show up above
+    for v in right.iter() {
+        if g.neighbors_of(v).unwrap().any(|w| right_index.contains_key(w)) {
+            return Err(GraphError::NotBipartite);
+        }
+    }
+
+    let mate_of_left = maximum_matching(&adjacency, right.len());
+
+    let mut pairs: Vec<(T, T)> = Vec::new();
+    let mut mates: HashMap<T, T> = HashMap::new();
+    for (i, mate) in mate_of_left.into_iter().enumerate() {
+        if let Some(j) = mate {
+            let (u, v) = (left[i].clone(), right[j].clone());
+            mates.insert(u.clone(), v.clone());
+            mates.insert(v.clone(), u.clone());
+            pairs.push((u, v));
+        }
+    }
+
+    Ok(Matching { pairs, mates })
+}
+
+/// The actual implementation of Hopcroft-Karp, with the left vertices as
+/// `0..adjacency.len()` and the right ones as `0..num_right`. Returns the
+/// mate of every left vertex.
+fn maximum_matching(adjacency: &[Vec<usize>], num_right: usize) -> Vec<Option<usize>> {
+    let num_left = adjacency.len();
+    let mut mate_of_left: Vec<Option<usize>> = vec![None; num_left];
+    let mut mate_of_right: Vec<Option<usize>> = vec![None; num_right];
+
+    // Length of the shortest alternating path from a free left vertex to
+    // each left vertex, for the current phase
+    let mut layers: Vec<Option<usize>> = vec![None; num_left];
+    let mut next_edge: Vec<usize> = vec![0; num_left];
+    let mut queue: VecDeque<usize> = VecDeque::new();
+    loop {
+        // Split the graph into layers, stopping at the first layer that
+        // reaches a free right vertex
+        let mut free_layer: Option<usize> = None;
+        for (u, mate) in mate_of_left.iter().enumerate() {
+            layers[u] = if mate.is_none() {
+                queue.push_back(u);
+                Some(0)
+            } else {
+                None
+            };
+        }
+        while let Some(u) = queue.pop_front() {
+            let layer = layers[u].unwrap();
+            if free_layer.is_some_and(|free| layer >= free) {
+                continue;
+            }
+            for &v in adjacency[u].iter() {
+                match mate_of_right[v] {
+                    None => {
+                        free_layer.get_or_insert(layer + 1);
+                    }
+                    Some(w) if layers[w].is_none() => {
+                        layers[w] = Some(layer + 1);
+                        queue.push_back(w);
+                    }
+                    Some(_) => ()
+                }
+            }
+        }
+        let free_layer = match free_layer {
+            Some(free) => free,
+            None => break
+        };
+
+        // Then flip as many vertex-disjoint shortest augmenting paths as
+        // possible, using a depth-first search without recursion. The
+        // stack holds the left vertices on the current path, and the right
+        // vertex after each one is the last edge it tried.
+        next_edge.iter_mut().for_each(|i| *i = 0);
+        for root in 0..num_left {
+            if mate_of_left[root].is_some() {
+                continue;
+            }
+            let mut stack: Vec<usize> = vec![root];
+            while let Some(&u) = stack.last() {
+                if next_edge[u] == adjacency[u].len() {
+                    // Dead end, no point in coming back here this phase
+                    layers[u] = None;
+                    stack.pop();
+                    continue;
+                }
+                let v = adjacency[u][next_edge[u]];
+                next_edge[u] += 1;
+                let next_layer = layers[u].map(|l| l + 1);
+                match mate_of_right[v] {
+                    None if next_layer == Some(free_layer) => {
+                        for &w in stack.iter() {
+                            let x = adjacency[w][next_edge[w] - 1];
+                            mate_of_left[w] = Some(x);
+                            mate_of_right[x] = Some(w);
+                        }
+                        // Nothing on this path can be used again this phase
+                        for &w in stack.iter() {
+                            layers[w] = None;
+                        }
+                        break;
+                    }
+                    Some(w) if layers[w].is_some() && layers[w] == next_layer => stack.push(w),
+                    _ => ()
+                }
+            }
+        }
+    }
+
+    mate_of_left
+}
@@ -0,0 +1,355 @@
+use std::hash::Hash;
+use std::iter::Enumerate;
+use std::slice::Iter;
+use super::digraph::DiGraph;
+use super::directed_graph::DirectedGraph;
+use super::error::GraphError;
+use super::vertex_lookup::VertexLookup;
+use crate::misc::bitset::{self, BitSet};
+
+/// A directed graph stored as an adjacency matrix, with one bit per
+/// possible edge. Meant for dense graphs, where most of the V^2 possible
+/// edges are there anyway.
+///
+/// Checking for an edge is O(1), and going through the neighbors of a
+/// vertex is O(V / 64) no matter how many there are. The matrix always
+/// takes O(V^2 / 64) memory, which is far less than a DiGraph needs for a
+/// nearly complete graph, but far more for a sparse one.
+///
+/// There's only one bit per pair of vertices, so there are no parallel
+/// edges and no payloads. The neighbors of a vertex come out in the order
+/// the vertices were added, not the order the edges were.
+///
+/// Implements `DirectedGraph`, so the graph algorithms work on it the same
+/// way they do on a DiGraph. Except for the ones that read the payloads as
+/// weights or capacities, like `dijkstra` or `dinic`: there's nothing to read.
+#[derive(Debug, Clone)]
+pub struct MatrixGraph<T: Clone + Eq + Hash> {
+    vertices: Vec<T>,
+    // Finds the position of a vertex by its hash, so it isn't stored twice.
+    // A matrix with more than u32::MAX rows wouldn't fit in memory anyway.
+    lookup: VertexLookup,
+    // rows[u] holds v if there is an edge from u to v
+    rows: Vec<BitSet>,
+    num_edges: usize
+}
+
+impl<T: Clone + Eq + Hash> Default for MatrixGraph<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone + Eq + Hash> MatrixGraph<T> {
+    /// Constructs a new, empty MatrixGraph
+    pub fn new() -> MatrixGraph<T> {
+        MatrixGraph {
+            vertices: Vec::new(),
+            lookup: VertexLookup::default(),
+            rows: Vec::new(),
+            num_edges: 0
+        }
+    }
+
+    /// Returns the number of vertices present in the graph
+    pub fn num_vertices(&self) -> usize {
+        self.vertices.len()
+    }
+
+    /// Returns the number of edges present in the graph
+    pub fn num_edges(&self) -> usize {
+        self.num_edges
+    }
+
+    /// Returns an iterator over every vertex in the graph, in the order they
+    /// were added
+    pub fn vertices(&self) -> Iter<'_, T> {
+        self.vertices.iter()
+    }
+
+    /// Returns the position of the vertex in the matrix, or `None` if it is
+    /// not in the graph. The vertices are numbered `0..num_vertices()` in the
+    /// order they were added.
+    pub fn index_of(&self, node: &T) -> Option<usize> {
+        self.lookup.get(&self.vertices, node).map(|index| index as usize)
+    }
+
+    /// Returns the vertex at the given position, or `None` if there is no
+    /// such position
+    pub fn vertex(&self, index: usize) -> Option<&T> {
+        self.vertices.get(index)
+    }
+
+    /// Adds a vertex to the graph. Returns `Ok(())` if the operation was
+    /// successful, but `Err(GraphError::DuplicateVertex)` if it was already
+    /// in the graph.
+    ///
+    /// Every row of the matrix needs to make room for the new vertex, so
+    /// this is an `O(V)` operation.
+    pub fn add_vertex(&mut self, node: T) -> Result<(), GraphError> {
+        if self.contains(&node) {
+            return Err(GraphError::DuplicateVertex);
+        }
+        self.insert_vertex(node);
+        Ok(())
+    }
+
+    fn insert_vertex(&mut self, node: T) -> usize {
+        let index = self.vertices.len();
+        let n = index + 1;
+        for row in self.rows.iter_mut() {
+            row.grow(n);
+        }
+        self.rows.push(BitSet::new(n));
+        self.lookup.insert(&node, index as u32);
+        self.vertices.push(node);
+        index
+    }
+
+    /// Returns `true` if the query vertex exists in the graph, `false` otherwise
+    pub fn contains(&self, node: &T) -> bool {
+        self.index_of(node).is_some()
+    }
+
+    /// Returns the graph's own copy of the query vertex, which lives as long
+    /// as the graph is borrowed. Or `None` if the vertex is not in the graph.
+    pub fn find_vertex(&self, node: &T) -> Option<&T> {
+        self.index_of(node).map(|index| &self.vertices[index])
+    }
+
+    /// Adds a directed edge between `u` and `v`. Returns `Ok(())` if the
+    /// operation was successful, but `Err(GraphError::MissingSourceVertex)`
+    /// if `u` does not exist in the graph. Like with a DiGraph, `v` is added
+    /// to the graph if it isn't there yet.
+    ///
+    /// Adding an edge that is already there does nothing.
+    pub fn add_edge(&mut self, u: &T, v: &T) -> Result<(), GraphError> {
+        let u = self.index_of(u).ok_or(GraphError::MissingSourceVertex)?;
+        let v = match self.index_of(v) {
+            Some(v) => v,
+            None => self.insert_vertex(v.clone())
+        };
+        if self.rows[u].insert(v) {
+            self.num_edges += 1;
+        }
+        Ok(())
+    }
+
+    /// Removes the directed edge between `u` and `v`, if it exists.
+    /// If the edge does not exist, this operation is idempotent.
+    pub fn remove_edge(&mut self, u: &T, v: &T) {
+        if let (Some(u), Some(v)) = (self.index_of(u), self.index_of(v)) {
+            if self.rows[u].remove(v) {
+                self.num_edges -= 1;
+            }
+        }
+    }
+
+    /// Returns `true` if there is a directed edge from `u` to `v`, `false` otherwise.
+    /// This is an `O(1)` operation
+    pub fn are_neighbors(&self, u: &T, v: &T) -> bool {
+        match (self.index_of(u), self.index_of(v)) {
+            (Some(u), Some(v)) => self.rows[u].contains(v),
+            _ => false
+        }
+    }
+
+    /// Returns an iterator over the neighbors of a given vertex, in the
+    /// order they were added to the graph.
+    /// Or `None` if the vertex does not exist in the graph.
+    pub fn neighbors_of(&self, node: &T) -> Option<Neighbors<'_, T>> {
+        let u = self.index_of(node)?;
+        Some(Neighbors { vertices: &self.vertices, row: self.rows[u].iter() })
+    }
+
+    /// Returns an iterator over the `(neighbor, payload)` pairs of every
+    /// edge that starts at the given vertex. There are no payloads, so each
+    /// one is just `()`. Or `None` if the vertex does not exist in the graph.
+    pub fn edges_of(&self, node: &T) -> Option<Edges<'_, T>> {
+        self.neighbors_of(node).map(|neighbors| Edges { neighbors })
+    }
+
+    /// Returns an iterator over the vertices that have an edge to the given
+    /// vertex, in the order they were added to the graph. Or `None` if the
+    /// vertex does not exist in the graph.
+    /// Every row has to be checked, so going through them all is `O(V)`.
+    pub fn predecessors_of(&self, node: &T) -> Option<Predecessors<'_, T>> {
+        let column = self.index_of(node)?;
+        Some(Predecessors { vertices: &self.vertices, rows: self.rows.iter().enumerate(), column })
+    }
+
+    /// Returns the number of directed edges that start at the query vertex.
+    /// Or `None` if the query vertex does not exist in the graph.
+    /// This is an `O(V / 64)` operation
+    pub fn out_degree(&self, node: &T) -> Option<usize> {
+        self.index_of(node).map(|u| self.rows[u].len())
+    }
+
+    /// Returns the number of directed edges that end at the query vertex.
+    /// Or `None` if the query vertex does not exist in the graph.
+    /// This is an `O(V)` operation, as it has to check every row.
+    pub fn in_degree(&self, node: &T) -> Option<usize> {
+        let v = self.index_of(node)?;
+        Some(self.rows.iter().filter(|row| row.contains(v)).count())
+    }
+
+    /// Copies the graph into a DiGraph. The vertices are added in the same
+    /// order, and so are the neighbors of each vertex.
+    pub fn to_digraph(&self) -> DiGraph<T> {
+        let mut g: DiGraph<T> = DiGraph::new();
+        for v in self.vertices.iter() {
+            // Safe to unwrap, every vertex is unique
+            g.add_vertex(v.clone()).unwrap();
+        }
+        for (u, row) in self.vertices.iter().zip(self.rows.iter()) {
+            for v in row.iter() {
+                // Safe to unwrap, u was added above
+                g.add_edge(u, &self.vertices[v]).unwrap();
+            }
+        }
+        g
+    }
+}
+
+impl<T: Clone + Eq + Hash> From<&DiGraph<T>> for MatrixGraph<T> {
+    /// Copies a DiGraph. The vertices keep their insertion order, and
+    /// parallel edges become a single edge.
+    fn from(g: &DiGraph<T>) -> Self {
+        let vertices: Vec<T> = g.vertices_in_insertion_order().into_iter().cloned().collect();
+        let mut lookup = VertexLookup::default();
+        for (i, v) in vertices.iter().enumerate() {
+            lookup.insert(v, i as u32);
+        }
+
+        // Every vertex is known up front, so each row is sized once
+        let n = vertices.len();
+        let mut rows = vec![BitSet::new(n); n];
+        let mut num_edges = 0;
+        for (u, row) in vertices.iter().zip(rows.iter_mut()) {
+            // Safe to unwrap, u came from the graph
+            for v in g.neighbors_of(u).unwrap() {
+                // Safe to unwrap, every neighbor is in the graph too
+                if row.insert(lookup.get(&vertices, v).unwrap() as usize) {
+                    num_edges += 1;
+                }
+            }
+        }
+
+        MatrixGraph { vertices, lookup, rows, num_edges }
+    }
+}
+
+impl<T: Clone + Eq + Hash> From<&MatrixGraph<T>> for DiGraph<T> {
+    fn from(g: &MatrixGraph<T>) -> Self {
+        g.to_digraph()
+    }
+}
+
+impl<T: Clone + Eq + Hash> DirectedGraph for MatrixGraph<T> {
+    type Vertex = T;
+    type Edge = ();
+    type Vertices<'a> = Iter<'a, T> where Self: 'a;
+    type Neighbors<'a> = Neighbors<'a, T> where Self: 'a;
+    type Predecessors<'a> = Predecessors<'a, T> where Self: 'a;
+    type Edges<'a> = Edges<'a, T> where Self: 'a;
+
+    fn num_vertices(&self) -> usize {
+        self.num_vertices()
+    }
+
+    fn num_edges(&self) -> usize {
+        self.num_edges()
+    }
+
+    fn contains(&self, node: &T) -> bool {
+        self.contains(node)
+    }
+
+    fn find_vertex(&self, node: &T) -> Option<&T> {
+        self.find_vertex(node)
+    }
+
+    fn vertices(&self) -> Self::Vertices<'_> {
+        self.vertices()
+    }
+
+    fn vertices_in_insertion_order(&self) -> Vec<&T> {
+        // Already in order, no sorting needed
+        self.vertices.iter().collect()
+    }
+
+    fn insertion_rank(&self, node: &T) -> Option<usize> {
+        self.index_of(node)
+    }
+
+    fn neighbors_of(&self, node: &T) -> Option<Self::Neighbors<'_>> {
+        self.neighbors_of(node)
+    }
+
+    fn edges_of(&self, node: &T) -> Option<Self::Edges<'_>> {
+        self.edges_of(node)
+    }
+
+    fn predecessors_of(&self, node: &T) -> Option<Self::Predecessors<'_>> {
+        self.predecessors_of(node)
+    }
+
+    fn out_degree(&self, node: &T) -> Option<usize> {
+        self.out_degree(node)
+    }
+
+    fn in_degree(&self, node: &T) -> Option<usize> {
+        self.in_degree(node)
+    }
+
+    fn are_neighbors(&self, u: &T, v: &T) -> bool {
+        self.are_neighbors(u, v)
+    }
+}
+
+/// Iterator over the neighbors of a vertex, which turns the bits of its row
+/// back into vertices. See `MatrixGraph::neighbors_of`.
+pub struct Neighbors<'a, T> {
+    vertices: &'a [T],
+    row: bitset::Iter<'a>
+}
+
+impl<'a, T> Iterator for Neighbors<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        self.row.next().map(|v| &self.vertices[v])
+    }
+}
+
+/// Iterator over the vertices that have an edge to a vertex, which checks
+/// the vertex's column in every row. See `MatrixGraph::predecessors_of`.
+pub struct Predecessors<'a, T> {
+    vertices: &'a [T],
+    rows: Enumerate<Iter<'a, BitSet>>,
+    column: usize
+}
+
+impl<'a, T> Iterator for Predecessors<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        let column = self.column;
+        let (u, _row) = self.rows.find(|(_u, row)| row.contains(column))?;
+        Some(&self.vertices[u])
+    }
+}
+
+/// Iterator over the `(neighbor, ())` pairs of the edges out of a vertex.
+/// See `MatrixGraph::edges_of`.
+pub struct Edges<'a, T> {
+    neighbors: Neighbors<'a, T>
+}
+
+impl<'a, T> Iterator for Edges<'a, T> {
+    type Item = (&'a T, &'a ());
+
+    fn next(&mut self) -> Option<(&'a T, &'a ())> {
+        self.neighbors.next().map(|v| (v, &()))
+    }
+}
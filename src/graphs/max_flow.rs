@@ -0,0 +1,301 @@
+use super::directed_graph::DirectedGraph;
+use super::error::GraphError;
+use super::weight::Weight;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+/// The result of a maximum flow algorithm: how much can be sent from the
+/// source to the sink, how much goes along each edge to get there, and a
+/// minimum cut proving that nothing more can be sent.
+#[derive(Debug, Clone)]
+pub struct MaxFlow<T: Clone + Eq + Hash, W> {
+    /// The total flow going from the source to the sink
+    pub value: W,
+    /// The flow along every edge of the graph, as `(u, v, flow)`. Parallel
+    /// edges each get their own entry. The edges are ordered by when their
+    /// start vertex was added to the graph, then by when they were added.
+    pub flows: Vec<(T, T, W)>,
+    /// The source side of a minimum s-t cut: every vertex that can still be
+    /// reached from the source through edges with capacity to spare. The
+    /// edges leaving this set are all full, and their capacities add up
+    /// to `value`.
+    pub source_side: HashSet<T>
+}
+
+impl<T: Clone + Eq + Hash, W: Weight> MaxFlow<T, W> {
+    /// Returns the total flow along every edge from `u` to `v`, which is
+    /// zero if there are no such edges.
+    pub fn flow_between(&self, u: &T, v: &T) -> W {
+        self.flows.iter()
+            .filter(|(from, to, _flow)| from == u && to == v)
+            .fold(W::zero(), |acc, (_from, _to, flow)| acc + *flow)
+    }
+
+    /// Returns the edges crossing the minimum cut, as `(u, v, flow)`. These
+    /// are the bottlenecks: every one of them is at full capacity, and
+    /// together they carry all of the flow.
+    pub fn cut_edges(&self) -> Vec<(T, T, W)> {
+        self.flows.iter()
+            .filter(|(u, v, _flow)| self.source_side.contains(u) && !self.source_side.contains(v))
+            .cloned()
+            .collect()
+    }
+}
+
+/// The Edmonds-Karp algorithm. Finds a maximum flow from `source` to `sink`,
+/// where the edge payloads are the capacities. Runs in O(VE^2).
+///
+/// Repeatedly sends as much flow as possible along a shortest path (by
+/// number of edges) that still has capacity to spare. See `dinic` for an
+/// algorithm that tends to be a lot faster on big graphs.
+///
+/// Returns `Err(GraphError::MissingSourceVertex)` or
+/// `Err(GraphError::MissingTargetVertex)` if `source` or `sink` is not in
+/// the graph, `Err(GraphError::SourceIsSink)` if they're the same vertex,
+/// and `Err(GraphError::NegativeWeight)` if any capacity is negative.
+pub fn edmonds_karp<G, T, W>(g: &G, source: &T, sink: &T) -> Result<MaxFlow<T, W>, GraphError>
+where
+    G: DirectedGraph<Vertex = T, Edge = W>,
+    T: Clone + Eq + Hash,
+    W: Weight
+{
+    let (mut network, s, t) = Network::new(g, source, sink)?;
+    let mut value = W::zero();
+
+    // Edge used to reach each vertex in the current search
+    let mut parent_edge: Vec<Option<usize>> = vec![None; network.num_vertices()];
+    let mut queue: VecDeque<usize> = VecDeque::new();
+    loop {
+        parent_edge.iter_mut().for_each(|e| *e = None);
+        queue.clear();
+        queue.push_back(s);
+        while let Some(u) = queue.pop_front() {
+            if u == t {
+                break;
+            }
+            for &e in network.adjacency[u].iter() {
+                let v = network.heads[e];
+                if v != s && parent_edge[v].is_none() && network.has_room(e) {
+                    parent_edge[v] = Some(e);
+                    queue.push_back(v);
+                }
+            }
+        }
+
+        if parent_edge[t].is_none() {
+            break;
+        }
+        let mut path: Vec<usize> = Vec::new();
+        let mut v = t;
+        while let Some(e) = parent_edge[v] {
+            path.push(e);
+            v = network.tail(e);
+        }
+        value = value + network.augment(&path);
+    }
+
+    Ok(network.into_max_flow(value, s))
+}
+
+/// Dinic's algorithm. Finds a maximum flow from `source` to `sink`, where
+/// the edge payloads are the capacities. Runs in O(V^2 E), and a lot faster
+/// than that on most graphs.
+///
+/// Works in phases. Each phase sorts the vertices into levels by their
+/// distance from the source, then pushes flow along paths that only ever
+/// go one level further, until there are none left.
+///
+/// Fails the same way `edmonds_karp` does.
+pub fn dinic<G, T, W>(g: &G, source: &T, sink: &T) -> Result<MaxFlow<T, W>, GraphError>
+where
+    G: DirectedGraph<Vertex = T, Edge = W>,
+    T: Clone + Eq + Hash,
+    W: Weight
+{
+    let (mut network, s, t) = Network::new(g, source, sink)?;
+    let n = network.num_vertices();
+    let mut value = W::zero();
+
+    let mut levels: Vec<Option<usize>> = vec![None; n];
+    // The next edge worth trying out of each vertex in this phase. Edges
+    // before it are known to lead nowhere useful.
+    let mut next_edge: Vec<usize> = vec![0; n];
+    let mut queue: VecDeque<usize> = VecDeque::new();
+    loop {
+        levels.iter_mut().for_each(|l| *l = None);
+        levels[s] = Some(0);
+        queue.push_back(s);
+        while let Some(u) = queue.pop_front() {
+            for &e in network.adjacency[u].iter() {
+                let v = network.heads[e];
+                if levels[v].is_none() && network.has_room(e) {
+                    levels[v] = levels[u].map(|l| l + 1);
+                    queue.push_back(v);
+                }
+            }
+        }
+        if levels[t].is_none() {
+            break;
+        }
+
+        // Find a blocking flow, one path at a time, without recursion
+        next_edge.iter_mut().for_each(|i| *i = 0);
+        let mut path: Vec<usize> = Vec::new();
+        let mut u = s;
+        loop {
+            if u == t {
+                value = value + network.augment(&path);
+                // Back up to just before the first edge that got full
+                let full = path.iter().position(|&e| !network.has_room(e)).unwrap_or(0);
+                path.truncate(full);
+                u = path.last().map_or(s, |&e| network.heads[e]);
+                continue;
+            }
+
+            let mut advanced = false;
+            while next_edge[u] < network.adjacency[u].len() {
+                let e = network.adjacency[u][next_edge[u]];
+                let v = network.heads[e];
+                if network.has_room(e) && levels[v].is_some() && levels[v] == levels[u].map(|l| l + 1) {
+                    path.push(e);
+                    u = v;
+                    advanced = true;
+                    break;
+                }
+                next_edge[u] += 1;
+            }
+
+            if !advanced {
+                // Dead end, nothing more can get through u this phase
+                levels[u] = None;
+                match path.pop() {
+                    Some(e) => {
+                        u = network.tail(e);
+                        next_edge[u] += 1;
+                    }
+                    None => break
+                }
+            }
+        }
+    }
+
+    Ok(network.into_max_flow(value, s))
+}
+
+/// The residual network used by the flow algorithms, with the vertices
+/// turned into indices.
+///
+/// Every edge of the graph becomes a pair of edges: edge `2k` going forward
+/// with the capacity left on it, and edge `2k + 1` going backward with the
+/// flow that could be pushed back (which is how much is flowing forward).
+/// That way the reverse of edge `e` is always `e ^ 1`.
+struct Network<'a, T, W> {
+    vertices: Vec<&'a T>,
+    adjacency: Vec<Vec<usize>>,
+    heads: Vec<usize>,
+    residuals: Vec<W>
+}
+
+impl<'a, T: Clone + Eq + Hash, W: Weight> Network<'a, T, W> {
+    /// Builds the network, along with the indices of the source and sink
+    fn new<G: DirectedGraph<Vertex = T, Edge = W>>(g: &'a G, source: &T, sink: &T) -> Result<(Network<'a, T, W>, usize, usize), GraphError> {
+        if !g.contains(source) {
+            return Err(GraphError::MissingSourceVertex);
+        }
+        if !g.contains(sink) {
+            return Err(GraphError::MissingTargetVertex);
+        }
+        if source == sink {
+            return Err(GraphError::SourceIsSink);
+        }
+
+        let vertices = g.vertices_in_insertion_order();
+        let index_of: HashMap<&T, usize> = vertices.iter()
+            .enumerate()
+            .map(|(i, v)| (*v, i))
+            .collect();
+
+        let mut network = Network {
+            adjacency: vec![Vec::new(); vertices.len()],
+            heads: Vec::new(),
+            residuals: Vec::new(),
+            vertices
+        };
+        for (u, vertex) in network.vertices.iter().enumerate() {
+            // Safe to unwrap, vertex came straight out of the graph
+            for (v, capacity) in g.edges_of(vertex).unwrap() {
+                if *capacity < W::zero() {
+                    return Err(GraphError::NegativeWeight);
+                }
+                let v = index_of[v];
+                // Always added, even for self-loops (which can never carry
+                // any flow), so that edge `2k` is the k-th entry of `MaxFlow::flows`
+                network.adjacency[u].push(network.heads.len());
+                network.heads.push(v);
+                network.residuals.push(*capacity);
+                network.adjacency[v].push(network.heads.len());
+                network.heads.push(u);
+                network.residuals.push(W::zero());
+            }
+        }
+
+        Ok((network, index_of[source], index_of[sink]))
+    }
+
+    fn num_vertices(&self) -> usize {
+        self.vertices.len()
+    }
+
+    /// The vertex that edge `e` starts from
+    fn tail(&self, e: usize) -> usize {
+        self.heads[e ^ 1]
+    }
+
+    /// Returns `true` if more flow can be pushed along edge `e`
+    fn has_room(&self, e: usize) -> bool {
+        self.residuals[e] > W::zero()
+    }
+
+    /// Pushes as much flow as possible along `path`, and returns how much that was
+    fn augment(&mut self, path: &[usize]) -> W {
+        let mut bottleneck = self.residuals[path[0]];
+        for &e in path.iter() {
+            if self.residuals[e] < bottleneck {
+                bottleneck = self.residuals[e];
+            }
+        }
+        for &e in path.iter() {
+            self.residuals[e] = self.residuals[e] - bottleneck;
+            self.residuals[e ^ 1] = self.residuals[e ^ 1] + bottleneck;
+        }
+        bottleneck
+    }
+
+    /// Reads the flows and the minimum cut out of the finished network
+    fn into_max_flow(self, value: W, s: usize) -> MaxFlow<T, W> {
+        let flows = (0..self.heads.len()).step_by(2)
+            .map(|e| (self.vertices[self.tail(e)].clone(), self.vertices[self.heads[e]].clone(), self.residuals[e ^ 1]))
+            .collect();
+
+        // Whatever the source can still reach in the residual network
+        let mut reached = vec![false; self.num_vertices()];
+        reached[s] = true;
+        let mut stack: Vec<usize> = vec![s];
+        while let Some(u) = stack.pop() {
+            for &e in self.adjacency[u].iter() {
+                let v = self.heads[e];
+                if !reached[v] && self.has_room(e) {
+                    reached[v] = true;
+                    stack.push(v);
+                }
+            }
+        }
+        let source_side = self.vertices.iter()
+            .zip(reached)
+            .filter(|(_v, reached)| *reached)
+            .map(|(v, _reached)| (*v).clone())
+            .collect();
+
+        MaxFlow { value, flows, source_side }
+    }
+}
@@ -0,0 +1,138 @@
+use super::graph::Graph;
+use super::weight::{MinScored, Weight};
+use crate::misc::union_find::UnionFind;
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::hash::Hash;
+
+/// A minimum spanning forest: one minimum spanning tree for every
+/// connected component of the graph. If the graph is connected, this is
+/// just a minimum spanning tree.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpanningForest<T, W> {
+    /// Every edge in the forest, as `(u, v, weight)`, in the order the
+    /// algorithm picked them
+    pub edges: Vec<(T, T, W)>,
+    /// The sum of the weights of every edge in the forest
+    pub total_weight: W,
+    /// The number of trees in the forest, which is the number of
+    /// connected components of the graph
+    pub num_trees: usize
+}
+
+impl<T, W> SpanningForest<T, W> {
+    /// Returns `true` if the forest is a single tree spanning the whole
+    /// graph, meaning the graph was connected (or empty).
+    pub fn is_tree(&self) -> bool {
+        self.num_trees <= 1
+    }
+}
+
+/// Kruskal's algorithm. Finds a minimum spanning forest of the graph, where
+/// the edge payloads are the weights. Runs in O(E log E).
+///
+/// Goes through the edges from lightest to heaviest, keeping each one that
+/// joins two different trees, which a union-find keeps track of. Edges with
+/// the same weight are considered in the order they were added.
+///
+/// Self-loops are never part of a spanning tree and are ignored. Negative
+/// weights are fine.
+pub fn kruskal<T, W>(g: &Graph<T, W>) -> SpanningForest<T, W>
+where
+    T: Clone + Eq + Hash,
+    W: Weight
+{
+    let vertices = g.vertices_in_insertion_order();
+    let index_of: HashMap<&T, usize> = vertices.iter()
+        .enumerate()
+        .map(|(i, v)| (*v, i))
+        .collect();
+
+    let mut candidates: Vec<(usize, usize, W)> = Vec::new();
+    for (i, u) in vertices.iter().enumerate() {
+        // Safe to unwrap, u came straight out of the graph
+        for (v, weight) in g.edges_of(u).unwrap() {
+            // Every edge is stored once going each way, only keep one of
+            // them. This also skips over self-loops.
+            let j = index_of[v];
+            if i < j {
+                candidates.push((i, j, *weight));
+            }
+        }
+    }
+    // Stable, so ties stay in insertion order
+    candidates.sort_by(|a, b| a.2.partial_cmp(&b.2).unwrap_or(Ordering::Equal));
+
+    let mut trees = UnionFind::new(vertices.len());
+    let mut edges: Vec<(T, T, W)> = Vec::new();
+    let mut total_weight = W::zero();
+    for (i, j, weight) in candidates {
+        if trees.union(i, j) {
+            edges.push((vertices[i].clone(), vertices[j].clone(), weight));
+            total_weight = total_weight + weight;
+            if trees.num_sets() == 1 {
+                break;
+            }
+        }
+    }
+
+    SpanningForest { edges, total_weight, num_trees: trees.num_sets() }
+}
+
+/// Prim's algorithm. Finds a minimum spanning forest of the graph, where
+/// the edge payloads are the weights. Runs in O(E log V).
+///
+/// Grows each tree one vertex at a time from a root, always adding the
+/// lightest edge leaving the tree, which a binary heap keeps track of. The
+/// roots are picked in insertion order, so each tree starts from the first
+/// vertex added in its component.
+///
+/// Self-loops are never part of a spanning tree and are ignored. Negative
+/// weights are fine.
+pub fn prim<T, W>(g: &Graph<T, W>) -> SpanningForest<T, W>
+where
+    T: Clone + Eq + Hash,
+    W: Weight
+{
+    let mut in_tree: HashSet<&T> = HashSet::new();
+    // Edges leaving the tree, as MinScored(weight, (inside, outside)).
+    // Edges whose far end joined the tree after they were pushed are
+    // skipped when popped, rather than searched for and removed.
+    let mut heap: BinaryHeap<MinScored<W, (&T, &T)>> = BinaryHeap::new();
+    let mut edges: Vec<(T, T, W)> = Vec::new();
+    let mut total_weight = W::zero();
+    let mut num_trees = 0;
+
+    for root in g.vertices_in_insertion_order() {
+        if !in_tree.insert(root) {
+            continue;
+        }
+        num_trees += 1;
+        push_leaving_edges(g, root, &in_tree, &mut heap);
+
+        while let Some(MinScored(weight, (u, v))) = heap.pop() {
+            if !in_tree.insert(v) {
+                continue;
+            }
+            edges.push((u.clone(), v.clone(), weight));
+            total_weight = total_weight + weight;
+            push_leaving_edges(g, v, &in_tree, &mut heap);
+        }
+    }
+
+    SpanningForest { edges, total_weight, num_trees }
+}
+
+/// Pushes every edge from `u` to a vertex that isn't in the tree yet
+fn push_leaving_edges<'a, T, W>(g: &'a Graph<T, W>, u: &'a T, in_tree: &HashSet<&T>, heap: &mut BinaryHeap<MinScored<W, (&'a T, &'a T)>>)
+where
+    T: Clone + Eq + Hash,
+    W: Weight
+{
+    // Safe to unwrap, u is always a vertex of the graph
+    for (v, weight) in g.edges_of(u).unwrap() {
+        if !in_tree.contains(v) {
+            heap.push(MinScored(*weight, (u, v)));
+        }
+    }
+}
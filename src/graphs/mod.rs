@@ -1,2 +1,25 @@
+pub mod a_star;
+pub mod all_pairs_shortest_paths;
+pub mod assignment;
+pub mod bellman_ford;
+pub mod biconnected;
+pub mod csr;
 pub mod digraph;
-pub mod topological_sorting;
\ No newline at end of file
+pub mod directed_graph;
+pub mod dijkstra;
+pub mod dominators;
+pub mod error;
+pub mod graph;
+pub mod indexed_graph;
+pub mod matching;
+pub mod matrix_graph;
+pub mod max_flow;
+pub mod minimum_spanning_tree;
+pub mod reachability;
+pub mod shortest_paths;
+pub mod strongly_connected;
+pub mod topological_sorting;
+pub mod transitive;
+pub mod traversal;
+mod vertex_lookup;
+pub mod weight;
\ No newline at end of file
@@ -0,0 +1,176 @@
+use super::directed_graph::DirectedGraph;
+use super::strongly_connected::condensation;
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+/// Precomputed answers to "is there a path from `u` to `v`?" for a graph,
+/// so that most questions take constant time instead of a whole search.
+///
+/// The strongly connected components are collapsed first, since every
+/// vertex in a component can reach exactly the same things. Then a single
+/// depth-first search over the (acyclic) condensation labels every
+/// component with two intervals:
+///
+/// - Its preorder range in the depth-first spanning forest. Everything in
+///   that range is a descendant in the forest, so it's definitely reachable.
+/// - Its postorder number, and the smallest postorder number it can reach.
+///   Anything reachable has its own interval nested inside that one, so a
+///   component whose interval sticks out is definitely not reachable.
+///
+/// Only when neither label decides does a query fall back to a search of
+/// the condensation, which skips every component the labels rule out.
+///
+/// Building the index takes O(V+E) time and memory, the same as a single
+/// search of the graph, so it scales to graphs of any size.
+///
+/// The index is a snapshot: it doesn't notice when the graph changes
+/// afterwards, and needs to be rebuilt with `rebuild`.
+#[derive(Debug, Clone)]
+pub struct ReachabilityIndex<T: Clone + Eq + Hash> {
+    component_of: HashMap<T, usize>,
+    labels: Vec<Label>,
+    // The edges of the condensation, packed into one array. The successors
+    // of component `c` are `successors[offsets[c]..offsets[c + 1]]`.
+    offsets: Vec<usize>,
+    successors: Vec<usize>
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct Label {
+    // Preorder number, and one past the last preorder number in the subtree
+    pre: usize,
+    end: usize,
+    // Postorder number, and the smallest postorder number of anything reachable
+    post: usize,
+    low: usize
+}
+
+impl<T: Clone + Eq + Hash> ReachabilityIndex<T> {
+    /// Builds the index for the graph as it is right now
+    pub fn new<G: DirectedGraph<Vertex = T>>(g: &G) -> ReachabilityIndex<T> {
+        let condensed = condensation(g);
+        let n = condensed.components.len();
+
+        let mut offsets: Vec<usize> = Vec::with_capacity(n + 1);
+        let mut successors: Vec<usize> = Vec::new();
+        offsets.push(0);
+        for c in 0..n {
+            // Safe to unwrap, every component is a vertex of the condensation
+            successors.extend(condensed.graph.neighbors_of(&c).unwrap());
+            offsets.push(successors.len());
+        }
+
+        let mut labels: Vec<Label> = vec![Label::default(); n];
+        let mut visited: Vec<bool> = vec![false; n];
+        let mut next_pre: usize = 0;
+        let mut next_post: usize = 0;
+
+        // Iterative DFS, each stack entry is a component and how many of its
+        // successors have been looked at so far
+        for root in 0..n {
+            if visited[root] {
+                continue;
+            }
+            visited[root] = true;
+            labels[root].pre = next_pre;
+            next_pre += 1;
+            let mut stack: Vec<(usize, usize)> = vec![(root, offsets[root])];
+
+            while let Some((c, next)) = stack.last_mut() {
+                let c = *c;
+                if *next < offsets[c + 1] {
+                    let d = successors[*next];
+                    *next += 1;
+                    if !visited[d] {
+                        visited[d] = true;
+                        labels[d].pre = next_pre;
+                        next_pre += 1;
+                        stack.push((d, offsets[d]));
+                    }
+                } else {
+                    stack.pop();
+                    labels[c].end = next_pre;
+                    labels[c].post = next_post;
+                    next_post += 1;
+                }
+            }
+        }
+
+        // The components are in topological order, so going backwards every
+        // successor's `low` is final before it's needed
+        for c in (0..n).rev() {
+            let low = successors[offsets[c]..offsets[c + 1]].iter()
+                .map(|d| labels[*d].low)
+                .fold(labels[c].post, usize::min);
+            labels[c].low = low;
+        }
+
+        ReachabilityIndex { component_of: condensed.component_of, labels, offsets, successors }
+    }
+
+    /// Throws away the index and builds it again from `g`, which is
+    /// usually the same graph after it was changed.
+    pub fn rebuild<G: DirectedGraph<Vertex = T>>(&mut self, g: &G) {
+        *self = ReachabilityIndex::new(g);
+    }
+
+    /// Returns the number of vertices the index knows about
+    pub fn num_vertices(&self) -> usize {
+        self.component_of.len()
+    }
+
+    /// Returns `true` if the vertex was in the graph when the index was built
+    pub fn contains(&self, node: &T) -> bool {
+        self.component_of.contains_key(node)
+    }
+
+    /// Returns `true` if there is a path from `u` to `v`. Every vertex can
+    /// reach itself, with a path of no edges. Vertices that weren't in the
+    /// graph when the index was built can't reach, and can't be reached
+    /// from, anything.
+    pub fn reaches(&self, u: &T, v: &T) -> bool {
+        match (self.component_of.get(u), self.component_of.get(v)) {
+            (Some(cu), Some(cv)) => self.component_reaches(*cu, *cv),
+            _ => false
+        }
+    }
+
+    fn component_reaches(&self, from: usize, to: usize) -> bool {
+        if self.is_tree_descendant(from, to) {
+            return true;
+        }
+        if !self.may_reach(from, to) {
+            return false;
+        }
+
+        // The labels can't tell, so search, but only through components
+        // that could still lead to `to`
+        let mut seen: HashSet<usize> = HashSet::new();
+        let mut stack: Vec<usize> = vec![from];
+        seen.insert(from);
+        while let Some(c) = stack.pop() {
+            for d in self.successors[self.offsets[c]..self.offsets[c + 1]].iter() {
+                if self.is_tree_descendant(*d, to) {
+                    return true;
+                }
+                if self.may_reach(*d, to) && seen.insert(*d) {
+                    stack.push(*d);
+                }
+            }
+        }
+        false
+    }
+
+    /// `true` if `to` is in the subtree of `from` in the spanning forest,
+    /// which includes `from` itself
+    fn is_tree_descendant(&self, from: usize, to: usize) -> bool {
+        let (from, to) = (&self.labels[from], &self.labels[to]);
+        from.pre <= to.pre && to.pre < from.end
+    }
+
+    /// `false` if `from` definitely can't reach `to`
+    fn may_reach(&self, from: usize, to: usize) -> bool {
+        let (from, to) = (&self.labels[from], &self.labels[to]);
+        from.low <= to.low && to.post <= from.post
+    }
+}
@@ -0,0 +1,124 @@
+use super::error::GraphError;
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{self, Debug};
+use std::hash::Hash;
+
+/// A single path through a graph, along with its total cost.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Path<T, W> {
+    /// Every vertex along the path, starting with the first one and
+    /// ending with the last one.
+    pub vertices: Vec<T>,
+    /// The sum of the weights of every edge along the path
+    pub cost: W
+}
+
+/// The result of a single-source shortest path algorithm: the distance
+/// from the source to every vertex, and the shortest path tree needed to
+/// rebuild the paths themselves.
+///
+/// Vertices that can't be reached from the source don't have a distance
+/// at all, rather than some stand-in "infinity". They're listed by
+/// `unreachable` instead.
+#[derive(Debug, Clone)]
+pub struct ShortestPaths<T: Clone + Eq + Hash, W> {
+    source: T,
+    distances: HashMap<T, W>,
+    predecessors: HashMap<T, T>,
+    unreachable: Vec<T>
+}
+
+impl<T: Clone + Eq + Hash, W: Copy> ShortestPaths<T, W> {
+    pub(crate) fn new(source: T, distances: HashMap<T, W>, predecessors: HashMap<T, T>, unreachable: Vec<T>) -> ShortestPaths<T, W> {
+        ShortestPaths { source, distances, predecessors, unreachable }
+    }
+
+    /// The vertex that all the paths start from
+    pub fn source(&self) -> &T {
+        &self.source
+    }
+
+    /// Returns the length of the shortest path from the source to `v`,
+    /// or `None` if `v` can't be reached (or isn't in the graph at all).
+    pub fn distance_to(&self, v: &T) -> Option<W> {
+        self.distances.get(v).copied()
+    }
+
+    /// Returns `true` if there is any path from the source to `v`
+    pub fn is_reachable(&self, v: &T) -> bool {
+        self.distances.contains_key(v)
+    }
+
+    /// Returns the vertex right before `v` on the shortest path from the
+    /// source to `v`. Or `None` if `v` is the source or can't be reached.
+    pub fn predecessor_of(&self, v: &T) -> Option<&T> {
+        self.predecessors.get(v)
+    }
+
+    /// Every vertex of the graph that can't be reached from the source
+    pub fn unreachable(&self) -> &[T] {
+        &self.unreachable
+    }
+
+    /// Rebuilds the shortest path from the source to `v` by following the
+    /// predecessors backwards. Returns `None` if `v` can't be reached.
+    pub fn path_to(&self, v: &T) -> Option<Path<T, W>> {
+        let cost = self.distance_to(v)?;
+        let mut vertices: Vec<T> = vec![v.clone()];
+        let mut current = v;
+        while let Some(previous) = self.predecessors.get(current) {
+            vertices.push(previous.clone());
+            current = previous;
+        }
+        vertices.reverse();
+        Some(Path { vertices, cost })
+    }
+}
+
+/// Everything that can go wrong in a shortest path algorithm that allows
+/// negative edge weights.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ShortestPathError<T> {
+    /// The search couldn't start, see the inner error for why
+    Graph(GraphError),
+    /// The graph has a cycle whose total weight is negative, so there
+    /// are paths that are as short as you like. Each vertex has an edge
+    /// to the next one, and the last vertex has an edge back to the first.
+    NegativeCycle(Vec<T>)
+}
+
+impl<T: Debug> fmt::Display for ShortestPathError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ShortestPathError::Graph(err) => write!(f, "{}", err),
+            ShortestPathError::NegativeCycle(cycle) => {
+                write!(f, "Graph contains a negative cycle: ")?;
+                for v in cycle.iter() {
+                    write!(f, "{:?} -> ", v)?;
+                }
+                match cycle.first() {
+                    Some(first) => write!(f, "{:?}", first),
+                    None => Ok(())
+                }
+            }
+        }
+    }
+}
+
+impl<T: Debug> Error for ShortestPathError<T> {}
+
+impl<T> From<GraphError> for ShortestPathError<T> {
+    fn from(err: GraphError) -> ShortestPathError<T> {
+        ShortestPathError::Graph(err)
+    }
+}
+
+impl<T> From<ShortestPathError<T>> for GraphError {
+    fn from(err: ShortestPathError<T>) -> GraphError {
+        match err {
+            ShortestPathError::Graph(err) => err,
+            ShortestPathError::NegativeCycle(_) => GraphError::NegativeCycle
+        }
+    }
+}
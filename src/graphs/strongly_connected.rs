@@ -0,0 +1,145 @@
+use super::digraph::DiGraph;
+use super::directed_graph::DirectedGraph;
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+/// A graph with each of its strongly connected components collapsed
+/// into a single vertex. See `condensation`.
+pub struct Condensation<T: Clone + Eq + Hash> {
+    /// The collapsed graph. Its vertices are component ids, which index
+    /// into `components`. It is always acyclic.
+    pub graph: DiGraph<usize>,
+    /// The vertices of the original graph that make up each component.
+    /// The ids are in topological order: every edge of `graph` goes from
+    /// a smaller id to a larger one.
+    pub components: Vec<Vec<T>>,
+    /// Which component each vertex of the original graph ended up in
+    pub component_of: HashMap<T, usize>
+}
+
+/// Tarjan's algorithm for finding the strongly connected components of a
+/// graph. Runs in O(V+E).
+///
+/// Every vertex ends up in exactly one component. The components are
+/// returned in reverse topological order: if there is an edge from a
+/// vertex in component `a` to a vertex in component `b`, then `b` comes
+/// before `a` in the output.
+///
+/// The usual recursive formulation is unrolled into an explicit stack so
+/// that large graphs don't overflow the call stack.
+pub fn strongly_connected_components<G, T>(g: &G) -> Vec<Vec<T>>
+where
+    G: DirectedGraph<Vertex = T>,
+    T: Clone + Eq + Hash
+{
+    let mut components: Vec<Vec<T>> = Vec::new();
+
+    let mut next_index: usize = 0;
+    let mut index: HashMap<&T, usize> = HashMap::with_capacity(g.num_vertices());
+    let mut low_link: HashMap<&T, usize> = HashMap::with_capacity(g.num_vertices());
+    let mut on_stack: HashSet<&T> = HashSet::new();
+    let mut stack: Vec<&T> = Vec::new();
+
+    for root in g.vertices() {
+        if index.contains_key(root) {
+            continue;
+        }
+
+        // Each frame is a vertex along with the neighbors we haven't looked at yet
+        let mut call_stack: Vec<(&T, G::Neighbors<'_>)> = Vec::new();
+
+        index.insert(root, next_index);
+        low_link.insert(root, next_index);
+        next_index += 1;
+        stack.push(root);
+        on_stack.insert(root);
+        call_stack.push((root, g.neighbors_of(root).unwrap()));
+
+        while let Some((v, neighbors)) = call_stack.last_mut() {
+            let v: &T = v;
+            match neighbors.next() {
+                Some(w) if !index.contains_key(w) => {
+                    // Tree edge, "recurse" into w
+                    index.insert(w, next_index);
+                    low_link.insert(w, next_index);
+                    next_index += 1;
+                    stack.push(w);
+                    on_stack.insert(w);
+                    call_stack.push((w, g.neighbors_of(w).unwrap()));
+                }
+                Some(w) => {
+                    if on_stack.contains(w) {
+                        let w_index = index[w];
+                        let v_low = low_link.get_mut(v).unwrap();
+                        *v_low = (*v_low).min(w_index);
+                    }
+                }
+                None => {
+                    // Done with v, "return" to its parent
+                    call_stack.pop();
+                    let v_low = low_link[v];
+                    if let Some((parent, _)) = call_stack.last() {
+                        let parent_low = low_link.get_mut(parent).unwrap();
+                        *parent_low = (*parent_low).min(v_low);
+                    }
+
+                    // v is the root of a component, everything above it
+                    // on the stack belongs to the same component.
+                    if v_low == index[v] {
+                        let mut component = Vec::new();
+                        loop {
+                            let w = stack.pop().unwrap();
+                            on_stack.remove(w);
+                            component.push(w.clone());
+                            if w == v {
+                                break;
+                            }
+                        }
+                        components.push(component);
+                    }
+                }
+            }
+        }
+    }
+
+    components
+}
+
+/// Collapses every strongly connected component of the graph into a single
+/// vertex. There is an edge from component `a` to component `b` whenever
+/// some vertex in `a` has an edge to some vertex in `b`, and there are no
+/// duplicate edges or self-loops.
+///
+/// The result is always acyclic, so it can be handed straight to the
+/// topological sorting functions. Runs in O(V+E).
+pub fn condensation<G, T>(g: &G) -> Condensation<T>
+where
+    G: DirectedGraph<Vertex = T>,
+    T: Clone + Eq + Hash
+{
+    let mut components = strongly_connected_components(g);
+    // Tarjan's algorithm finds the components sinks first
+    components.reverse();
+
+    let mut component_of: HashMap<T, usize> = HashMap::with_capacity(g.num_vertices());
+    let mut graph: DiGraph<usize> = DiGraph::new();
+    for (id, component) in components.iter().enumerate() {
+        graph.add_vertex(id).unwrap();
+        for v in component {
+            component_of.insert(v.clone(), id);
+        }
+    }
+
+    let mut seen_edges: HashSet<(usize, usize)> = HashSet::new();
+    for u in g.vertices() {
+        let from = component_of[u];
+        for v in g.neighbors_of(u).unwrap() {
+            let to = component_of[v];
+            if from != to && seen_edges.insert((from, to)) {
+                graph.add_edge(&from, &to).unwrap();
+            }
+        }
+    }
+
+    Condensation { graph, components, component_of }
+}
@@ -1,30 +1,471 @@
-use super::digraph::DiGraph;
-use std::{collections::VecDeque, hash::Hash};
-use std::iter::FromIterator;
-use std::fmt::Debug;
-
-/// O(V*E) because of my implementation >:(
-/// Not exactly sure how to make in-degree a linear time operation
-pub fn topological_sort<T: Clone + Eq + Hash + Debug>(g: &mut DiGraph<T>) -> Option<Vec<T>> {
-    let mut topological_order: Vec<T> = Vec::new();
-    let mut candidates: VecDeque<T> = VecDeque::from_iter(g.get_source_vertices());
-
-    while !candidates.is_empty() {
-        let current = candidates.pop_front().unwrap();
+use super::directed_graph::DirectedGraph;
+use super::error::GraphError;
+use super::strongly_connected::strongly_connected_components;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::error::Error;
+use std::fmt::{self, Debug};
+use std::hash::Hash;
+
+/// Returned when a graph can't be topologically sorted because it has
+/// at least one cycle in it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CycleError<T> {
+    /// One concrete cycle in the graph. Each vertex has an edge to the
+    /// next one, and the last vertex has an edge back to the first.
+    pub cycle: Vec<T>,
+    /// Every strongly connected component that blocks the ordering.
+    /// That is, every component with more than one vertex or a self-loop.
+    /// The vertices of each component, and the components themselves (by
+    /// their earliest vertex), are sorted by when they were added to the
+    /// graph, so the same graph always gives the same error.
+    pub components: Vec<Vec<T>>,
+}
+
+impl<T: Debug> fmt::Display for CycleError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Graph contains a cycle: ")?;
+        for v in self.cycle.iter() {
+            write!(f, "{:?} -> ", v)?;
+        }
+        // Close the loop so the output reads naturally
+        match self.cycle.first() {
+            Some(first) => write!(f, "{:?}", first),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<T: Debug> Error for CycleError<T> {}
+
+impl<T> From<CycleError<T>> for GraphError {
+    fn from(_err: CycleError<T>) -> GraphError {
+        GraphError::CycleDetected
+    }
+}
+
+/// Kahn's algorithm. Runs in O(V+E) and leaves the graph untouched.
+///
+/// Instead of deleting edges as vertices are output, the in-degree of
+/// every vertex is copied into a local table once and decremented there.
+///
+/// If the graph is not acyclic, the error contains a cycle that
+/// prevented the sort from finishing.
+pub fn topological_sort<G, T>(g: &G) -> Result<Vec<T>, CycleError<T>>
+where
+    G: DirectedGraph<Vertex = T>,
+    T: Clone + Eq + Hash
+{
+    let mut topological_order: Vec<T> = Vec::with_capacity(g.num_vertices());
+    let (mut in_degrees, sources) = in_degree_table(g);
+    let mut candidates: VecDeque<&T> = VecDeque::from(sources);
+
+    while let Some(current) = candidates.pop_front() {
         topological_order.push(current.clone());
 
-        let neighbors: Vec<T> = g.neighbors_of(&current).unwrap().map(|k| k.to_owned()).collect();
-        for v in neighbors {
-            g.remove_edge(&current, &v);
-            if g.in_degree(&v).unwrap() == 0 {
-                candidates.push_back(v.clone());
+        for v in g.neighbors_of(current).unwrap() {
+            let degree = in_degrees.get_mut(v).unwrap();
+            *degree -= 1;
+            if *degree == 0 {
+                candidates.push_back(v);
+            }
+        }
+    }
+
+    // Every vertex on (or downstream of) a cycle never reaches an
+    // in-degree of 0, so it never makes it into the ordering.
+    if topological_order.len() < g.num_vertices() {
+        Err(cycle_error(g, &in_degrees))
+    } else {
+        Ok(topological_order)
+    }
+}
+
+/// Returns the lexicographically smallest topological ordering of the graph.
+///
+/// Whenever more than one vertex is ready to be output, the smallest one
+/// goes first. Unlike `topological_sort` the result is always the same for
+/// a given graph, no matter what order the backing HashMap iterates in.
+///
+/// Runs in O(V log V + E) because of the min-heap of ready vertices.
+pub fn lexicographic_topological_sort<G, T>(g: &G) -> Result<Vec<T>, CycleError<T>>
+where
+    G: DirectedGraph<Vertex = T>,
+    T: Clone + Eq + Hash + Ord
+{
+    prioritized_topological_sort(g, |v| v)
+}
+
+/// Returns the topological ordering of the graph that prefers vertices
+/// which were added to the graph earlier.
+///
+/// Whenever more than one vertex is ready to be output, the one with the
+/// smallest `DirectedGraph::insertion_rank` goes first. So if the graph already
+/// has no edges "against" the order the vertices were added in, that order
+/// is returned unchanged.
+///
+/// Runs in O(V log V + E) because of the min-heap of ready vertices.
+pub fn insertion_order_topological_sort<G, T>(g: &G) -> Result<Vec<T>, CycleError<T>>
+where
+    G: DirectedGraph<Vertex = T>,
+    T: Clone + Eq + Hash
+{
+    // Safe to unwrap, only vertices from the graph are ever prioritized
+    prioritized_topological_sort(g, |v| g.insertion_rank(v).unwrap())
+}
+
+/// Kahn's algorithm, but with a min-heap of ready vertices instead of a
+/// queue. The ready vertex with the smallest `priority` is always output next.
+fn prioritized_topological_sort<'a, G, T, P, F>(g: &'a G, priority: F) -> Result<Vec<T>, CycleError<T>>
+where
+    G: DirectedGraph<Vertex = T>,
+    T: Clone + Eq + Hash + 'a,
+    P: Ord,
+    F: Fn(&'a T) -> P
+{
+    let mut topological_order: Vec<T> = Vec::with_capacity(g.num_vertices());
+    let (mut in_degrees, sources) = in_degree_table(g);
+
+    // The heap holds indices into `ready`, that way only the priority
+    // needs to be comparable, not T itself.
+    let mut ready: Vec<&T> = Vec::with_capacity(g.num_vertices());
+    let mut candidates: BinaryHeap<Reverse<(P, usize)>> = BinaryHeap::new();
+
+    for v in sources {
+        candidates.push(Reverse((priority(v), ready.len())));
+        ready.push(v);
+    }
+
+    while let Some(Reverse((_priority, idx))) = candidates.pop() {
+        let current = ready[idx];
+        topological_order.push(current.clone());
+
+        for v in g.neighbors_of(current).unwrap() {
+            let degree = in_degrees.get_mut(v).unwrap();
+            *degree -= 1;
+            if *degree == 0 {
+                candidates.push(Reverse((priority(v), ready.len())));
+                ready.push(v);
+            }
+        }
+    }
+
+    if topological_order.len() < g.num_vertices() {
+        Err(cycle_error(g, &in_degrees))
+    } else {
+        Ok(topological_order)
+    }
+}
+
+/// Returns a lazy iterator over every topological ordering of the graph.
+///
+/// The orderings are generated by backtracking over the set of vertices
+/// with an in-degree of 0, trying the vertices in insertion order. So the
+/// first ordering produced is the same as `insertion_order_topological_sort`.
+///
+/// There can be up to V! orderings, so this is only practical for small
+/// graphs. A cyclic graph has no orderings at all, and the empty graph has
+/// exactly one: the empty ordering.
+pub fn all_topological_sorts<G, T>(g: &G) -> AllTopologicalSorts<'_, T>
+where
+    G: DirectedGraph<Vertex = T>,
+    T: Clone + Eq + Hash
+{
+    AllTopologicalSorts {
+        frontier: Frontier::new(g),
+        done: false
+    }
+}
+
+/// Counts the topological orderings of the graph without building any of them.
+///
+/// Vertices are placed one at a time just like in `all_topological_sorts`,
+/// but the number of ways to finish an ordering only depends on *which*
+/// vertices have been placed so far, not their order. And since a vertex
+/// can only be placed once everything before it is, the placed vertices
+/// are pinned down by the vertices that are ready to go next. So the number
+/// of ways to finish is memoized per set of ready vertices, which is far
+/// fewer than the number of orderings for most graphs. It is still
+/// exponential in the worst case.
+///
+/// Returns `Some(0)` for a cyclic graph, and `None` if there are more
+/// orderings than fit in a `u128`.
+pub fn count_topological_sorts<G, T>(g: &G) -> Option<u128>
+where
+    G: DirectedGraph<Vertex = T>,
+    T: Clone + Eq + Hash
+{
+    let mut frontier = Frontier::new(g);
+    let mut memo: HashMap<Vec<usize>, u128> = HashMap::new();
+
+    // Explicit stack instead of recursion, every vertex placed is one more
+    // level deep. Each level holds the vertices that were ready when it was
+    // reached, how many of them have been tried, and the orderings found so far.
+    let ready: Vec<usize> = (0..frontier.vertices.len()).filter(|v| frontier.is_ready(*v)).collect();
+    let mut stack: Vec<(Vec<usize>, usize, u128)> = vec![(ready, 0, 0)];
+
+    loop {
+        // Safe to unwrap, the stack is only emptied by returning
+        let (ready, tried, count) = stack.last_mut().unwrap();
+
+        if *tried < ready.len() {
+            let v = ready[*tried];
+            *tried += 1;
+            frontier.place(v);
+
+            // The vertices that are ready next are the ones that still are,
+            // plus any successors of `v` that were only waiting on `v`
+            let mut next: Vec<usize> = ready.iter().copied().filter(|w| *w != v).collect();
+            next.extend(frontier.successors[v].iter().copied().filter(|w| frontier.in_degrees[*w] == 0));
+            next.sort_unstable();
+            next.dedup();
+
+            if frontier.is_complete() {
+                *count = count.checked_add(1)?;
+                frontier.unplace();
+            } else if let Some(completions) = memo.get(&next) {
+                *count = count.checked_add(*completions)?;
+                frontier.unplace();
+            } else {
+                stack.push((next, 0, 0));
+            }
+        } else {
+            // Every ready vertex has been tried, so this level is finished
+            let (ready, _, completions) = stack.pop().unwrap();
+            memo.insert(ready, completions);
+            match stack.last_mut() {
+                Some((_, _, count)) => {
+                    *count = count.checked_add(completions)?;
+                    frontier.unplace();
+                }
+                // The empty graph has exactly one ordering, the empty one
+                None if frontier.vertices.is_empty() => return Some(1),
+                None => return Some(completions)
+            }
+        }
+    }
+}
+
+/// Iterator over every topological ordering of a graph.
+/// See `all_topological_sorts`.
+pub struct AllTopologicalSorts<'a, T> {
+    frontier: Frontier<'a, T>,
+    done: bool
+}
+
+impl<'a, T: Clone> Iterator for AllTopologicalSorts<'a, T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Vec<T>> {
+        if self.done {
+            return None;
+        }
+        let n = self.frontier.vertices.len();
+        if n == 0 {
+            self.done = true;
+            return Some(Vec::new());
+        }
+
+        // The vertex to start searching from at the current depth. Every
+        // vertex before it has already been tried in this position.
+        let mut start: usize = 0;
+
+        // The last call handed out a complete ordering, step back from it
+        if self.frontier.is_complete() {
+            start = self.frontier.unplace() + 1;
+        }
+
+        loop {
+            match (start..n).find(|v| self.frontier.is_ready(*v)) {
+                Some(v) => {
+                    self.frontier.place(v);
+                    start = 0;
+                    if self.frontier.is_complete() {
+                        return Some(self.frontier.ordering());
+                    }
+                }
+                None => {
+                    // Nothing left to try at this depth, backtrack
+                    if self.frontier.order.is_empty() {
+                        self.done = true;
+                        return None;
+                    }
+                    start = self.frontier.unplace() + 1;
+                }
             }
         }
     }
+}
+
+/// A partially built topological ordering, with vertices referred to by
+/// their index (in insertion order) so that placing and un-placing a vertex
+/// is cheap.
+struct Frontier<'a, T> {
+    vertices: Vec<&'a T>,
+    successors: Vec<Vec<usize>>,
+    // In-degrees counting only the edges from vertices that aren't placed yet
+    in_degrees: Vec<usize>,
+    // Bitset of the vertices in `order`
+    placed: Vec<u64>,
+    order: Vec<usize>
+}
+
+impl<'a, T: Clone + Eq + Hash> Frontier<'a, T> {
+    fn new<G: DirectedGraph<Vertex = T>>(g: &'a G) -> Frontier<'a, T> {
+        let vertices: Vec<&T> = g.vertices_in_insertion_order();
+
+        let indices: HashMap<&T, usize> = vertices.iter().enumerate().map(|(i, v)| (*v, i)).collect();
+        let successors = vertices.iter()
+            .map(|v| g.neighbors_of(v).unwrap().map(|w| indices[w]).collect())
+            .collect();
+        let in_degrees = vertices.iter().map(|v| g.in_degree(v).unwrap()).collect();
+
+        Frontier {
+            placed: vec![0; vertices.len().div_ceil(64)],
+            order: Vec::with_capacity(vertices.len()),
+            vertices,
+            successors,
+            in_degrees
+        }
+    }
+}
+
+impl<'a, T> Frontier<'a, T> {
+    fn is_complete(&self) -> bool {
+        self.order.len() == self.vertices.len()
+    }
+
+    fn is_ready(&self, v: usize) -> bool {
+        self.placed[v / 64] & (1 << (v % 64)) == 0 && self.in_degrees[v] == 0
+    }
+
+    fn place(&mut self, v: usize) {
+        self.placed[v / 64] |= 1 << (v % 64);
+        self.order.push(v);
+        for w in self.successors[v].iter() {
+            self.in_degrees[*w] -= 1;
+        }
+    }
+
+    /// Takes back the most recently placed vertex, and returns it
+    fn unplace(&mut self) -> usize {
+        let v = self.order.pop().unwrap();
+        self.placed[v / 64] &= !(1 << (v % 64));
+        for w in self.successors[v].iter() {
+            self.in_degrees[*w] += 1;
+        }
+        v
+    }
 
-    if g.num_edges() > 0 {
-        None
+    fn ordering(&self) -> Vec<T> where T: Clone {
+        self.order.iter().map(|v| self.vertices[*v].clone()).collect()
+    }
+}
+
+/// Groups the vertices of the graph into layers, where every vertex only
+/// depends on (has incoming edges from) vertices in earlier layers.
+///
+/// The first layer is every vertex with an in-degree of 0, and each layer
+/// after that is every vertex whose last remaining dependency was in the
+/// previous layer. So all the vertices within a layer are independent of
+/// each other and could be processed at the same time.
+///
+/// Like `topological_sort` this runs in O(V+E) and leaves the graph untouched.
+pub fn topological_layers<G, T>(g: &G) -> Result<Vec<Vec<T>>, CycleError<T>>
+where
+    G: DirectedGraph<Vertex = T>,
+    T: Clone + Eq + Hash
+{
+    let mut layers: Vec<Vec<T>> = Vec::new();
+    let mut num_sorted: usize = 0;
+    let (mut in_degrees, mut current_layer) = in_degree_table(g);
+
+    while !current_layer.is_empty() {
+        let mut next_layer: Vec<&T> = Vec::new();
+
+        for current in current_layer.iter() {
+            for v in g.neighbors_of(current).unwrap() {
+                let degree = in_degrees.get_mut(v).unwrap();
+                *degree -= 1;
+                if *degree == 0 {
+                    next_layer.push(v);
+                }
+            }
+        }
+
+        num_sorted += current_layer.len();
+        layers.push(current_layer.into_iter().cloned().collect());
+        current_layer = next_layer;
+    }
+
+    if num_sorted < g.num_vertices() {
+        Err(cycle_error(g, &in_degrees))
     } else {
-        Some(topological_order)
+        Ok(layers)
+    }
+}
+
+/// Copies the in-degree of every vertex into a table, and collects all the
+/// vertices that start out with an in-degree of 0.
+fn in_degree_table<G, T>(g: &G) -> (HashMap<&T, usize>, Vec<&T>)
+where
+    G: DirectedGraph<Vertex = T>,
+    T: Clone + Eq + Hash
+{
+    let mut in_degrees: HashMap<&T, usize> = HashMap::with_capacity(g.num_vertices());
+    let mut sources: Vec<&T> = Vec::new();
+
+    for v in g.vertices() {
+        // Safe to unwrap, `v` came straight out of the graph
+        let degree = g.in_degree(v).unwrap();
+        if degree == 0 {
+            sources.push(v);
+        }
+        in_degrees.insert(v, degree);
     }
-}
\ No newline at end of file
+
+    (in_degrees, sources)
+}
+
+/// Builds the error for a failed sort, given the in-degree table as
+/// Kahn's algorithm left it.
+///
+/// Every vertex with a leftover in-degree still has a predecessor that
+/// was never output, so walking backwards along those predecessors must
+/// eventually repeat a vertex. The walk between the repeats is a cycle.
+fn cycle_error<G, T>(g: &G, in_degrees: &HashMap<&T, usize>) -> CycleError<T>
+where
+    G: DirectedGraph<Vertex = T>,
+    T: Clone + Eq + Hash
+{
+    let is_blocked = |v: &T| in_degrees[v] > 0;
+
+    // There is at least one blocked vertex, otherwise the sort would've succeeded.
+    // Starting from the earliest one keeps the error the same from run to run.
+    let mut current: &T = g.vertices_in_insertion_order().into_iter().find(|v| is_blocked(v)).unwrap();
+    let mut walk: Vec<&T> = Vec::new();
+    let mut position: HashMap<&T, usize> = HashMap::new();
+
+    while !position.contains_key(current) {
+        position.insert(current, walk.len());
+        walk.push(current);
+        current = g.predecessors_of(current).unwrap().find(|u| is_blocked(u)).unwrap();
+    }
+
+    // The walk went against the edges, so flip it back around
+    let mut cycle: Vec<T> = walk[position[current]..].iter().map(|v| (*v).clone()).collect();
+    cycle.reverse();
+
+    // Safe to unwrap, every vertex in a component is in the graph
+    let rank = |v: &T| g.insertion_rank(v).unwrap();
+    let mut components: Vec<Vec<T>> = strongly_connected_components(g)
+        .into_iter()
+        .filter(|c| c.len() > 1 || g.are_neighbors(&c[0], &c[0]))
+        .collect();
+    for component in components.iter_mut() {
+        component.sort_by_cached_key(|v| rank(v));
+    }
+    components.sort_by_cached_key(|c| rank(&c[0]));
+
+    CycleError { cycle, components }
+}
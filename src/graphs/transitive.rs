@@ -0,0 +1,154 @@
+use super::digraph::DiGraph;
+use super::directed_graph::DirectedGraph;
+use super::strongly_connected::{condensation, Condensation};
+use super::topological_sorting::{topological_sort, CycleError};
+use crate::misc::bitset::BitSet;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Builds the transitive closure of the graph: a graph with the same
+/// vertices, and an edge from `u` to `v` whenever there's a path from `u`
+/// to `v` in the original graph. A vertex only gets a self-loop if it's on
+/// a cycle.
+///
+/// Works on any graph, cycles and all. The vertices are added in insertion
+/// order, and so are the edges out of each vertex.
+///
+/// Takes O(V^2 / 64) memory for the bitsets, on top of the closure itself,
+/// which can have up to V^2 edges. Runs in O(V E / 64), plus the time needed
+/// to build the closure.
+pub fn transitive_closure<G, T>(g: &G) -> DiGraph<T>
+where
+    G: DirectedGraph<Vertex = T>,
+    T: Clone + Eq + Hash
+{
+    let condensed = condensation(g);
+    let reach = component_closure(g, &condensed);
+
+    let vertices = g.vertices_in_insertion_order();
+    let mut closure: DiGraph<T> = DiGraph::new();
+    for v in vertices.iter() {
+        // Safe to unwrap, every vertex is unique
+        closure.add_vertex((*v).clone()).unwrap();
+    }
+    // Every vertex in a component reaches the same vertices, so only work
+    // those out once per component
+    let mut targets: Vec<Option<Vec<&T>>> = vec![None; reach.len()];
+    for u in vertices.iter() {
+        let c = condensed.component_of[*u];
+        let targets = targets[c].get_or_insert_with(|| {
+            let mut targets: Vec<&T> = reach[c].iter()
+                .flat_map(|d| condensed.components[d].iter())
+                .collect();
+            // Safe to unwrap, these are all vertices of the graph
+            targets.sort_by_cached_key(|v| g.insertion_rank(v).unwrap());
+            targets
+        });
+        for v in targets.iter() {
+            // Safe to unwrap, u was added above
+            closure.add_edge(u, v).unwrap();
+        }
+    }
+
+    closure
+}
+
+/// Builds the transitive reduction of a DAG: the graph with the fewest
+/// edges that still has a path from `u` to `v` exactly when the original
+/// graph does. For a DAG, that's unique, and it's exactly the original
+/// edges that aren't implied by some longer path.
+///
+/// The edges that are kept carry their original payloads. The vertices are
+/// added in insertion order, and the edges out of each vertex stay in the
+/// order they were added. Of a bunch of parallel edges, only the first one
+/// is kept.
+///
+/// If the graph is not acyclic, the error contains a cycle that got in the
+/// way. Takes O(V^2 / 64) memory and O(V E / 64) time, on top of sorting
+/// the edges out of each vertex.
+pub fn transitive_reduction<G, T, E>(g: &G) -> Result<DiGraph<T, E>, CycleError<T>>
+where
+    G: DirectedGraph<Vertex = T, Edge = E>,
+    T: Clone + Eq + Hash,
+    E: Clone
+{
+    let order = topological_sort(g)?;
+    let position: HashMap<&T, usize> = order.iter()
+        .enumerate()
+        .map(|(i, v)| (v, i))
+        .collect();
+    let n = order.len();
+
+    // Go from the back, so that everything after a vertex is done by the
+    // time it's needed. The children of each vertex are looked at in
+    // topological order too: a child that can be reached through another
+    // child always comes after it, so it's already covered when reached.
+    let mut reach: Vec<BitSet> = vec![BitSet::new(n); n];
+    let mut kept: Vec<Vec<usize>> = vec![Vec::new(); n];
+    for u in (0..n).rev() {
+        // Safe to unwrap, every vertex in the order is in the graph
+        let mut children: Vec<usize> = g.neighbors_of(&order[u]).unwrap()
+            .map(|v| position[v])
+            .collect();
+        children.sort_unstable();
+
+        let (done, rest) = reach.split_at_mut(u + 1);
+        let covered = &mut done[u];
+        for v in children {
+            if covered.insert(v) {
+                kept[u].push(v);
+                covered.union_with(&rest[v - u - 1]);
+            }
+        }
+    }
+
+    let mut reduction: DiGraph<T, E> = DiGraph::new();
+    let vertices = g.vertices_in_insertion_order();
+    for v in vertices.iter() {
+        // Safe to unwrap, every vertex is unique
+        reduction.add_vertex((*v).clone()).unwrap();
+    }
+    let mut keep = BitSet::new(n);
+    for u in vertices.iter() {
+        for v in kept[position[*u]].iter() {
+            keep.insert(*v);
+        }
+        for (v, payload) in g.edges_of(u).unwrap() {
+            // Removing it means only the first of any parallel edges is kept
+            if keep.remove(position[v]) {
+                // Safe to unwrap, u was added above
+                reduction.add_edge_with(u, v, payload.clone()).unwrap();
+            }
+        }
+    }
+
+    Ok(reduction)
+}
+
+/// Works out which components of the condensation can be reached from each
+/// component, going backwards through the topological order. A component
+/// only reaches itself if it's on a cycle: if it has more than one vertex,
+/// or a self-loop in `g`.
+pub(crate) fn component_closure<G, T>(g: &G, condensed: &Condensation<T>) -> Vec<BitSet>
+where
+    G: DirectedGraph<Vertex = T>,
+    T: Clone + Eq + Hash
+{
+    let n = condensed.components.len();
+    let mut reach: Vec<BitSet> = vec![BitSet::new(n); n];
+    for c in (0..n).rev() {
+        // Everything after c is done, and the edges only ever go forward
+        let (done, rest) = reach.split_at_mut(c + 1);
+        let reachable = &mut done[c];
+        let members = &condensed.components[c];
+        if members.len() > 1 || g.are_neighbors(&members[0], &members[0]) {
+            reachable.insert(c);
+        }
+        // Safe to unwrap, the ids are exactly the vertices of the condensation
+        for d in condensed.graph.neighbors_of(&c).unwrap() {
+            reachable.insert(*d);
+            reachable.union_with(&rest[*d - c - 1]);
+        }
+    }
+    reach
+}
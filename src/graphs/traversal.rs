@@ -0,0 +1,221 @@
+use super::directed_graph::DirectedGraph;
+use super::error::GraphError;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::vec::IntoIter;
+
+/// Lazy breadth-first traversal of any of the directed graph types.
+///
+/// Yields each vertex once, the first time it is reached. Vertices are
+/// reached in order of how many edges away from the start they are.
+pub struct Bfs<'a, G: DirectedGraph> {
+    graph: &'a G,
+    queue: VecDeque<&'a G::Vertex>,
+    discovered: HashSet<&'a G::Vertex>,
+    // Where to restart once everything reachable has been visited
+    roots: IntoIter<&'a G::Vertex>
+}
+
+impl<'a, G: DirectedGraph> Bfs<'a, G> {
+    /// Starts a traversal at `start`, which only visits the vertices
+    /// reachable from it. Returns `Err(GraphError::MissingVertex)` if
+    /// `start` is not in the graph.
+    pub fn new(g: &'a G, start: &G::Vertex) -> Result<Bfs<'a, G>, GraphError> {
+        let start = g.find_vertex(start).ok_or(GraphError::MissingVertex)?;
+        let mut bfs = Bfs {
+            graph: g,
+            queue: VecDeque::new(),
+            discovered: HashSet::new(),
+            roots: Vec::new().into_iter()
+        };
+        bfs.discovered.insert(start);
+        bfs.queue.push_back(start);
+        Ok(bfs)
+    }
+
+    /// Starts a traversal that covers the whole graph. Whenever the
+    /// vertices reachable so far run out, the traversal restarts at the
+    /// earliest added vertex that hasn't been visited yet.
+    pub fn full(g: &'a G) -> Bfs<'a, G> {
+        Bfs {
+            graph: g,
+            queue: VecDeque::new(),
+            discovered: HashSet::new(),
+            roots: g.vertices_in_insertion_order().into_iter()
+        }
+    }
+}
+
+impl<'a, G: DirectedGraph> Iterator for Bfs<'a, G> {
+    type Item = &'a G::Vertex;
+
+    fn next(&mut self) -> Option<&'a G::Vertex> {
+        if self.queue.is_empty() {
+            let discovered = &self.discovered;
+            let root = self.roots.find(|v| !discovered.contains(v))?;
+            self.discovered.insert(root);
+            self.queue.push_back(root);
+        }
+
+        let current = self.queue.pop_front()?;
+        for v in self.graph.neighbors_of(current).unwrap() {
+            if self.discovered.insert(v) {
+                self.queue.push_back(v);
+            }
+        }
+        Some(current)
+    }
+}
+
+/// Lazy depth-first traversal of any of the directed graph types.
+///
+/// Yields each vertex once, in preorder: a vertex comes out right when it
+/// is discovered, before anything reachable through it. The order matches
+/// the usual recursive formulation, but there's no recursion involved.
+pub struct Dfs<'a, G: DirectedGraph + 'a> {
+    graph: &'a G,
+    // Each frame is a vertex's neighbors we haven't looked at yet
+    stack: Vec<G::Neighbors<'a>>,
+    discovered: HashSet<&'a G::Vertex>,
+    roots: IntoIter<&'a G::Vertex>
+}
+
+impl<'a, G: DirectedGraph> Dfs<'a, G> {
+    /// Starts a traversal at `start`, which only visits the vertices
+    /// reachable from it. Returns `Err(GraphError::MissingVertex)` if
+    /// `start` is not in the graph.
+    pub fn new(g: &'a G, start: &G::Vertex) -> Result<Dfs<'a, G>, GraphError> {
+        let start = g.find_vertex(start).ok_or(GraphError::MissingVertex)?;
+        Ok(Dfs {
+            graph: g,
+            stack: Vec::new(),
+            discovered: HashSet::new(),
+            roots: vec![start].into_iter()
+        })
+    }
+
+    /// Starts a traversal that covers the whole graph. Whenever the
+    /// vertices reachable so far run out, the traversal restarts at the
+    /// earliest added vertex that hasn't been visited yet.
+    pub fn full(g: &'a G) -> Dfs<'a, G> {
+        Dfs {
+            graph: g,
+            stack: Vec::new(),
+            discovered: HashSet::new(),
+            roots: g.vertices_in_insertion_order().into_iter()
+        }
+    }
+
+    fn discover(&mut self, v: &'a G::Vertex) -> &'a G::Vertex {
+        self.discovered.insert(v);
+        self.stack.push(self.graph.neighbors_of(v).unwrap());
+        v
+    }
+}
+
+impl<'a, G: DirectedGraph> Iterator for Dfs<'a, G> {
+    type Item = &'a G::Vertex;
+
+    fn next(&mut self) -> Option<&'a G::Vertex> {
+        while let Some(neighbors) = self.stack.last_mut() {
+            match neighbors.next() {
+                Some(v) if !self.discovered.contains(v) => return Some(self.discover(v)),
+                Some(_) => {}
+                None => {
+                    self.stack.pop();
+                }
+            }
+        }
+
+        let discovered = &self.discovered;
+        let root = self.roots.find(|v| !discovered.contains(v))?;
+        Some(self.discover(root))
+    }
+}
+
+/// Everything that happens over the course of a depth-first search,
+/// in the order it happens.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DfsEvent<'a, T> {
+    /// The vertex was reached for the first time
+    Discover(&'a T),
+    /// The edge led to an undiscovered vertex, which is about to be discovered
+    TreeEdge(&'a T, &'a T),
+    /// The edge leads back to a vertex that is still being explored,
+    /// so it closes a cycle
+    BackEdge(&'a T, &'a T),
+    /// The edge leads to an already finished descendant of the start vertex
+    ForwardEdge(&'a T, &'a T),
+    /// The edge leads to an already finished vertex that is not a descendant
+    /// of the start vertex, in this tree or an earlier one
+    CrossEdge(&'a T, &'a T),
+    /// Everything reachable from the vertex has been explored
+    Finish(&'a T)
+}
+
+/// Runs a depth-first search from `start`, and calls `visitor` for every
+/// event along the way. See `DfsEvent`.
+///
+/// Returns `Err(GraphError::MissingVertex)` if `start` is not in the graph.
+pub fn depth_first_visit<'a, G, F>(g: &'a G, start: &G::Vertex, visitor: F) -> Result<(), GraphError>
+where
+    G: DirectedGraph,
+    F: FnMut(DfsEvent<'a, G::Vertex>)
+{
+    let start = g.find_vertex(start).ok_or(GraphError::MissingVertex)?;
+    visit(g, vec![start], visitor);
+    Ok(())
+}
+
+/// Runs a depth-first search over the whole graph, and calls `visitor` for
+/// every event along the way. New searches are started from the earliest
+/// added vertex that hasn't been discovered yet, until every vertex has been.
+pub fn depth_first_visit_all<'a, G, F>(g: &'a G, visitor: F)
+where
+    G: DirectedGraph,
+    F: FnMut(DfsEvent<'a, G::Vertex>)
+{
+    visit(g, g.vertices_in_insertion_order(), visitor);
+}
+
+fn visit<'a, G, F, R>(g: &'a G, roots: R, mut visitor: F)
+where
+    G: DirectedGraph,
+    F: FnMut(DfsEvent<'a, G::Vertex>),
+    R: IntoIterator<Item = &'a G::Vertex>
+{
+    // When each vertex was discovered. Needed to tell forward and cross edges apart.
+    let mut discovery_time: HashMap<&G::Vertex, usize> = HashMap::new();
+    let mut finished: HashSet<&G::Vertex> = HashSet::new();
+
+    for root in roots {
+        if discovery_time.contains_key(root) {
+            continue;
+        }
+
+        discovery_time.insert(root, discovery_time.len());
+        visitor(DfsEvent::Discover(root));
+        let mut stack: Vec<(&G::Vertex, G::Neighbors<'a>)> = vec![(root, g.neighbors_of(root).unwrap())];
+
+        while let Some((u, neighbors)) = stack.last_mut() {
+            let u: &G::Vertex = u;
+            match neighbors.next() {
+                Some(v) => match discovery_time.get(v) {
+                    None => {
+                        visitor(DfsEvent::TreeEdge(u, v));
+                        discovery_time.insert(v, discovery_time.len());
+                        visitor(DfsEvent::Discover(v));
+                        stack.push((v, g.neighbors_of(v).unwrap()));
+                    }
+                    Some(_) if !finished.contains(v) => visitor(DfsEvent::BackEdge(u, v)),
+                    Some(v_time) if discovery_time[u] < *v_time => visitor(DfsEvent::ForwardEdge(u, v)),
+                    Some(_) => visitor(DfsEvent::CrossEdge(u, v))
+                },
+                None => {
+                    stack.pop();
+                    finished.insert(u);
+                    visitor(DfsEvent::Finish(u));
+                }
+            }
+        }
+    }
+}
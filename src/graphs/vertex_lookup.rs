@@ -0,0 +1,43 @@
+use std::collections::hash_map::{Entry, RandomState};
+use std::collections::HashMap;
+use std::hash::{BuildHasher, Hash};
+
+/// Finds where a vertex sits in a graph's `Vec` of vertices, without keeping
+/// a second copy of the vertex around as a `HashMap` key would.
+///
+/// Only the hash of each vertex is kept, along with its index. Two different
+/// vertices practically never share a 64 bit hash, but if they do, every
+/// vertex after the first goes in a short list that's searched by comparing
+/// the vertices themselves.
+///
+/// The graph owns the vertices, so it has to pass them back in on every
+/// lookup. Vertices can only be added, never removed.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct VertexLookup {
+    hasher: RandomState,
+    first: HashMap<u64, u32>,
+    collisions: Vec<u32>
+}
+
+impl VertexLookup {
+    /// Records that `node` is at `index`. `node` must not already be in here.
+    pub(crate) fn insert<T: Hash>(&mut self, node: &T, index: u32) {
+        match self.first.entry(self.hasher.hash_one(node)) {
+            Entry::Vacant(slot) => {
+                slot.insert(index);
+            }
+            Entry::Occupied(_) => self.collisions.push(index)
+        }
+    }
+
+    /// Returns the index of `node` in `vertices`, or `None` if it was never
+    /// inserted. `vertices` has to be the same list the indices point into.
+    pub(crate) fn get<T: Eq + Hash>(&self, vertices: &[T], node: &T) -> Option<u32> {
+        let index = *self.first.get(&self.hasher.hash_one(node))?;
+        if vertices[index as usize] == *node {
+            Some(index)
+        } else {
+            self.collisions.iter().copied().find(|i| vertices[*i as usize] == *node)
+        }
+    }
+}
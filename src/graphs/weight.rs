@@ -0,0 +1,56 @@
+use std::cmp::Ordering;
+use std::ops::{Add, Sub};
+
+/// Numeric types that can be used as edge weights by the weighted graph
+/// algorithms (shortest paths, spanning trees, flows, ...).
+///
+/// This is implemented for all of the built-in integer and floating point
+/// types. The algorithms only ever compare weights with `PartialOrd`, so
+/// floats work too as long as they aren't NaN.
+pub trait Weight: Copy + PartialOrd + Add<Output = Self> + Sub<Output = Self> {
+    /// The additive identity, the length of an empty path
+    fn zero() -> Self;
+}
+
+macro_rules! impl_weight {
+    ($($t:ty),*) => {
+        $(
+            impl Weight for $t {
+                fn zero() -> Self {
+                    0 as $t
+                }
+            }
+        )*
+    };
+}
+
+impl_weight!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
+/// Pairs a vertex with a score, and orders them so that a `BinaryHeap`
+/// (which is a max-heap) pops the *smallest* score first.
+///
+/// Only the score takes part in the comparison, so the vertex doesn't
+/// need to be orderable, and scores that can't be compared (NaN) are
+/// treated as equal rather than panicking.
+pub(crate) struct MinScored<W, V>(pub W, pub V);
+
+impl<W: PartialOrd, V> PartialEq for MinScored<W, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<W: PartialOrd, V> Eq for MinScored<W, V> {}
+
+impl<W: PartialOrd, V> PartialOrd for MinScored<W, V> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<W: PartialOrd, V> Ord for MinScored<W, V> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Backwards on purpose, smaller scores are "greater"
+        other.0.partial_cmp(&self.0).unwrap_or(Ordering::Equal)
+    }
+}
@@ -9,6 +9,12 @@ pub struct Stack<T> {
     buf: Vec<T>,
 }
 
+impl<T> Default for Stack<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<T> Stack<T> {
     /// Create a new stack
     pub fn new() -> Stack<T> {
@@ -22,6 +28,11 @@ impl<T> Stack<T> {
         self.buf.len()
     }
 
+    /// Returns `true` if there are no elements on the stack.
+    pub fn is_empty(&self) -> bool {
+        self.buf.is_empty()
+    }
+
     /// Adds a new item to the top of the stack
     pub fn push(&mut self, item: T) {
         self.buf.push(item);
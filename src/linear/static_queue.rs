@@ -13,6 +13,12 @@ pub struct SQueue<T : Default> {
     rear: usize
 }
 
+impl<T : Default> Default for SQueue<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<T : Default> SQueue<T> {
     /// Constructs a new, empty, SQueue
     pub fn new() -> SQueue<T> {
@@ -85,4 +91,9 @@ impl<T : Default> SQueue<T> {
     pub fn len(&self) -> usize {
         self.len
     }
+
+    /// Returns `true` if the SQueue contains no elements
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
 }
\ No newline at end of file
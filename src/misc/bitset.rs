@@ -0,0 +1,117 @@
+/// Fixed-size set of the integers `0..capacity`, stored as one bit each.
+///
+/// Much smaller and faster than a `HashSet<usize>` when the elements are
+/// dense, and whole sets can be merged 64 elements at a time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BitSet {
+    words: Vec<u64>,
+    capacity: usize
+}
+
+impl BitSet {
+    /// Creates an empty set that can hold `0..capacity`
+    pub fn new(capacity: usize) -> BitSet {
+        BitSet {
+            words: vec![0; capacity.div_ceil(64)],
+            capacity
+        }
+    }
+
+    /// Returns how many different elements the set can hold
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Makes room for `0..capacity`, keeping every element already in the
+    /// set. Does nothing if the set can already hold that many.
+    pub fn grow(&mut self, capacity: usize) {
+        if capacity > self.capacity {
+            self.words.resize(capacity.div_ceil(64), 0);
+            self.capacity = capacity;
+        }
+    }
+
+    /// Returns the number of elements in the set.
+    /// This is proportional to the capacity, not the number of elements.
+    pub fn len(&self) -> usize {
+        self.words.iter().map(|word| word.count_ones() as usize).sum()
+    }
+
+    /// Returns `true` if there are no elements in the set
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|word| *word == 0)
+    }
+
+    /// Returns `true` if `i` is in the set. Anything past the capacity
+    /// is never in the set.
+    pub fn contains(&self, i: usize) -> bool {
+        i < self.capacity && self.words[i / 64] & (1 << (i % 64)) != 0
+    }
+
+    /// Adds `i` to the set. Returns `true` if it wasn't there already.
+    ///
+    /// Panics if `i` is not less than the capacity.
+    pub fn insert(&mut self, i: usize) -> bool {
+        assert!(i < self.capacity, "{} is out of bounds for a BitSet of capacity {}", i, self.capacity);
+        let was_there = self.contains(i);
+        self.words[i / 64] |= 1 << (i % 64);
+        !was_there
+    }
+
+    /// Removes `i` from the set. Returns `true` if it was there.
+    pub fn remove(&mut self, i: usize) -> bool {
+        let was_there = self.contains(i);
+        if was_there {
+            self.words[i / 64] &= !(1 << (i % 64));
+        }
+        was_there
+    }
+
+    /// Removes every element from the set
+    pub fn clear(&mut self) {
+        self.words.iter_mut().for_each(|word| *word = 0);
+    }
+
+    /// Adds every element of `other` to this set.
+    ///
+    /// Panics if the two sets don't have the same capacity.
+    pub fn union_with(&mut self, other: &BitSet) {
+        assert_eq!(self.capacity, other.capacity, "Can't merge BitSets of different capacities");
+        for (word, other_word) in self.words.iter_mut().zip(other.words.iter()) {
+            *word |= *other_word;
+        }
+    }
+
+    /// Returns an iterator over the elements of the set, smallest first
+    pub fn iter(&self) -> Iter<'_> {
+        Iter {
+            words: &self.words,
+            index: 0,
+            current: self.words.first().copied().unwrap_or(0)
+        }
+    }
+}
+
+/// Iterator over the elements of a BitSet. See `BitSet::iter`.
+pub struct Iter<'a> {
+    words: &'a [u64],
+    /// Which word `current` came from
+    index: usize,
+    /// The bits of the current word that haven't been returned yet
+    current: u64
+}
+
+impl Iterator for Iter<'_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        while self.current == 0 {
+            self.index += 1;
+            self.current = *self.words.get(self.index)?;
+        }
+        let bit = self.current.trailing_zeros() as usize;
+        // Clear the lowest set bit
+        self.current &= self.current - 1;
+        Some(self.index * 64 + bit)
+    }
+}
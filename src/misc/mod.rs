@@ -1 +1,3 @@
-pub mod lfsr;
\ No newline at end of file
+pub mod bitset;
+pub mod lfsr;
+pub mod union_find;
\ No newline at end of file
@@ -0,0 +1,85 @@
+use std::cmp::Ordering;
+
+/// Union-find (aka disjoint-set forest) over the integers `0..n`.
+///
+/// Keeps track of a partition of the elements into disjoint sets, and can
+/// merge two sets or check whether two elements are in the same set in
+/// (practically) constant time. Uses union by rank and path halving.
+///
+/// Elements are plain indices rather than arbitrary values, anything else
+/// can be mapped to an index first.
+pub struct UnionFind {
+    parents: Vec<usize>,
+    ranks: Vec<u8>,
+    num_sets: usize
+}
+
+impl UnionFind {
+    /// Creates `n` singleton sets, one for each of `0..n`
+    pub fn new(n: usize) -> UnionFind {
+        UnionFind {
+            parents: (0..n).collect(),
+            ranks: vec![0; n],
+            num_sets: n
+        }
+    }
+
+    /// Returns the number of elements
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    /// Returns `true` if there are no elements at all
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+
+    /// Returns the number of disjoint sets left
+    pub fn num_sets(&self) -> usize {
+        self.num_sets
+    }
+
+    /// Returns the representative of the set containing `x`. Two elements
+    /// are in the same set exactly when they have the same representative.
+    ///
+    /// Panics if `x` is out of bounds.
+    pub fn find(&mut self, mut x: usize) -> usize {
+        while self.parents[x] != x {
+            // Path halving: point every other node on the way at its grandparent
+            self.parents[x] = self.parents[self.parents[x]];
+            x = self.parents[x];
+        }
+        x
+    }
+
+    /// Merges the sets containing `x` and `y`. Returns `true` if they were
+    /// different sets, `false` if they were already the same set.
+    ///
+    /// Panics if `x` or `y` is out of bounds.
+    pub fn union(&mut self, x: usize, y: usize) -> bool {
+        let x = self.find(x);
+        let y = self.find(y);
+        if x == y {
+            return false;
+        }
+
+        // Hang the shallower tree off of the deeper one
+        match self.ranks[x].cmp(&self.ranks[y]) {
+            Ordering::Less => self.parents[x] = y,
+            Ordering::Greater => self.parents[y] = x,
+            Ordering::Equal => {
+                self.parents[y] = x;
+                self.ranks[x] += 1;
+            }
+        }
+        self.num_sets -= 1;
+        true
+    }
+
+    /// Returns `true` if `x` and `y` are in the same set
+    ///
+    /// Panics if `x` or `y` is out of bounds.
+    pub fn connected(&mut self, x: usize, y: usize) -> bool {
+        self.find(x) == self.find(y)
+    }
+}
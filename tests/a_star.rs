@@ -0,0 +1,132 @@
+#[cfg(test)]
+mod a_star {
+    use dsa_in_rust::graphs::a_star::*;
+    use dsa_in_rust::graphs::digraph::DiGraph;
+    use dsa_in_rust::graphs::dijkstra::dijkstra_to;
+    use dsa_in_rust::graphs::error::GraphError;
+
+    type Cell = (i64, i64);
+
+    /// A `size` by `size` grid where you can step up, down, left, or right,
+    /// except through the wall at x == 5 (which has a gap at the top).
+    fn helper_make_grid(size: i64) -> DiGraph<Cell, u32> {
+        let mut g: DiGraph<Cell, u32> = DiGraph::new();
+        let is_open = |(x, y): Cell| x >= 0 && y >= 0 && x < size && y < size && (x != 5 || y == 0);
+
+        for x in 0..size {
+            for y in 0..size {
+                if is_open((x, y)) {
+                    g.add_vertex((x, y)).expect("Adding a unique vertex should've worked!");
+                }
+            }
+        }
+        for x in 0..size {
+            for y in 0..size {
+                if !is_open((x, y)) {
+                    continue;
+                }
+                for (dx, dy) in [(1, 0), (-1, 0), (0, 1), (0, -1)].iter() {
+                    let next = (x + dx, y + dy);
+                    if is_open(next) {
+                        g.add_edge_with(&(x, y), &next, 1).expect("Adding this edge should've worked!");
+                    }
+                }
+            }
+        }
+
+        g
+    }
+
+    fn manhattan(a: &Cell, b: &Cell) -> u32 {
+        ((a.0 - b.0).abs() + (a.1 - b.1).abs()) as u32
+    }
+
+    #[test]
+    fn finds_shortest_path_around_wall() {
+        let g = helper_make_grid(10);
+        let goal: Cell = (9, 9);
+
+        let path = a_star(&g, &(0, 9), |v| *v == goal, |_, _, w| *w, |v| manhattan(v, &goal))
+            .expect("The start is in the graph!")
+            .expect("The goal is reachable!");
+
+        // Up to the gap in the wall, through it, and back down
+        assert_eq!(path.cost, 27);
+        assert_eq!(path.vertices.len(), 28);
+        assert_eq!(path.vertices.first(), Some(&(0, 9)));
+        assert_eq!(path.vertices.last(), Some(&goal));
+        assert!(path.vertices.contains(&(5, 0)));
+        for step in path.vertices.windows(2) {
+            assert!(g.are_neighbors(&step[0], &step[1]));
+        }
+
+        let expected = dijkstra_to(&g, &(0, 9), &goal).unwrap().unwrap();
+        assert_eq!(path.cost, expected.cost);
+    }
+
+    #[test]
+    fn heuristic_cuts_down_exploration() {
+        let g = helper_make_grid(30);
+        let goal: Cell = (4, 29);
+
+        let mut guided: usize = 0;
+        let path = a_star(&g, &(0, 0), |v| { guided += 1; *v == goal }, |_, _, w| *w, |v| manhattan(v, &goal))
+            .unwrap()
+            .unwrap();
+        assert_eq!(path.cost, 33);
+
+        let mut blind: usize = 0;
+        let path = a_star(&g, &(0, 0), |v| { blind += 1; *v == goal }, |_, _, w| *w, |_| 0)
+            .unwrap()
+            .unwrap();
+        assert_eq!(path.cost, 33);
+
+        assert!(guided < blind);
+    }
+
+    #[test]
+    fn any_goal_will_do() {
+        let g = helper_make_grid(10);
+
+        // The closest cell on the far side of the wall
+        let path = a_star(&g, &(0, 0), |v| v.0 > 5, |_, _, w| *w, |v| (6 - v.0).max(0) as u32)
+            .unwrap()
+            .unwrap();
+        assert_eq!(path.vertices.last(), Some(&(6, 0)));
+        assert_eq!(path.cost, 6);
+    }
+
+    #[test]
+    fn custom_edge_costs() {
+        let g = helper_make_grid(10);
+        let goal: Cell = (0, 3);
+
+        // Going straight costs 3, but make moving along x = 0 expensive
+        let cost = |u: &Cell, v: &Cell, _w: &u32| if u.0 == 0 && v.0 == 0 { 10 } else { 1 };
+        let path = a_star(&g, &(0, 0), |v| *v == goal, cost, |v| manhattan(v, &goal))
+            .unwrap()
+            .unwrap();
+        assert_eq!(path.cost, 5);
+        assert_eq!(path.vertices, vec![(0, 0), (1, 0), (1, 1), (1, 2), (1, 3), (0, 3)]);
+    }
+
+    #[test]
+    fn unreachable_goal_is_none() {
+        let mut g = helper_make_grid(10);
+        g.add_vertex((100, 100)).expect("Adding a unique vertex should've worked!");
+
+        let result = a_star(&g, &(0, 0), |v| *v == (100, 100), |_, _, w| *w, |_| 0u32);
+        assert_eq!(result, Ok(None));
+    }
+
+    #[test]
+    fn bad_inputs_are_err() {
+        let g = helper_make_grid(10);
+
+        let result = a_star(&g, &(-1, -1), |_| true, |_, _, w| *w, |_| 0u32);
+        assert_eq!(result, Err(GraphError::MissingSourceVertex));
+
+        let result = a_star(&g, &(0, 0), |v| *v == (9, 9), |_, _, _| -1i64, |_| 0i64);
+        assert_eq!(result, Err(GraphError::NegativeWeight));
+    }
+}
@@ -0,0 +1,198 @@
+#[cfg(test)]
+mod all_pairs_shortest_paths {
+    use dsa_in_rust::graphs::all_pairs_shortest_paths::*;
+    use dsa_in_rust::graphs::bellman_ford::bellman_ford;
+    use dsa_in_rust::graphs::digraph::DiGraph;
+    use dsa_in_rust::graphs::shortest_paths::ShortestPathError;
+    use dsa_in_rust::misc::lfsr::LFSR;
+
+    fn helper_make_graph() -> DiGraph<i64, i64> {
+        // See: https://en.wikipedia.org/wiki/Johnson%27s_algorithm
+        // Plus a vertex nothing can reach, and one that can't reach anything
+        let mut g: DiGraph<i64, i64> = DiGraph::new();
+
+        for v in 1..=6 {
+            g.add_vertex(v).expect("Adding a unique vertex should've worked!");
+        }
+        g.add_edge_with(&1, &2, -2).expect("Adding this edge should've worked!");
+        g.add_edge_with(&2, &1, 4).expect("Adding this edge should've worked!");
+        g.add_edge_with(&2, &3, -1).expect("Adding this edge should've worked!");
+        g.add_edge_with(&3, &4, 2).expect("Adding this edge should've worked!");
+        g.add_edge_with(&4, &1, 3).expect("Adding this edge should've worked!");
+        g.add_edge_with(&4, &2, 1).expect("Adding this edge should've worked!");
+        g.add_edge_with(&4, &2, 5).expect("Adding this edge should've worked!");
+        g.add_edge_with(&5, &4, -4).expect("Adding this edge should've worked!");
+        g.add_edge_with(&3, &6, 7).expect("Adding this edge should've worked!");
+
+        g
+    }
+
+    fn helper_cheapest_edge(g: &DiGraph<i64, i64>, u: &i64, v: &i64) -> i64 {
+        g.edges_of(u).unwrap()
+            .filter(|(w, _)| w == v)
+            .map(|(_, weight)| *weight)
+            .min()
+            .expect("Every hop should be an edge!")
+    }
+
+    fn helper_check_against_bellman_ford(g: &DiGraph<i64, i64>, all_pairs: &AllPairsShortestPaths<i64, i64>) {
+        for u in g.vertices() {
+            let single = bellman_ford(g, u).expect("There are no negative cycles!");
+            for v in g.vertices() {
+                assert_eq!(all_pairs.distance(u, v), single.distance_to(v));
+
+                // Every path must be made of real edges and add up to the distance
+                match all_pairs.path(u, v) {
+                    None => assert!(!single.is_reachable(v)),
+                    Some(path) => {
+                        assert_eq!(path.vertices.first(), Some(u));
+                        assert_eq!(path.vertices.last(), Some(v));
+                        let total: i64 = path.vertices.windows(2)
+                            .map(|hop| helper_cheapest_edge(g, &hop[0], &hop[1]))
+                            .sum();
+                        assert_eq!(total, path.cost);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn floyd_warshall_matches_bellman_ford() {
+        let g = helper_make_graph();
+        let all_pairs = floyd_warshall(&g).expect("There are no negative cycles!");
+        helper_check_against_bellman_ford(&g, &all_pairs);
+    }
+
+    #[test]
+    fn johnson_matches_bellman_ford() {
+        let g = helper_make_graph();
+        let all_pairs = johnson(&g).expect("There are no negative cycles!");
+        helper_check_against_bellman_ford(&g, &all_pairs);
+    }
+
+    #[test]
+    fn distances_and_next_hops() {
+        let g = helper_make_graph();
+
+        for all_pairs in [floyd_warshall(&g), johnson(&g)].iter() {
+            let all_pairs = all_pairs.as_ref().expect("There are no negative cycles!");
+
+            assert_eq!(all_pairs.distance(&5, &1), Some(-1));
+            assert_eq!(all_pairs.path(&5, &1).unwrap().vertices, vec![5, 4, 1]);
+            assert_eq!(all_pairs.distance(&1, &4), Some(-1));
+            assert_eq!(all_pairs.next_hop(&1, &4), Some(&2));
+            assert_eq!(all_pairs.next_hop(&2, &4), Some(&3));
+            assert_eq!(all_pairs.distance(&3, &3), Some(0));
+            assert_eq!(all_pairs.next_hop(&3, &3), None);
+            assert_eq!(all_pairs.path(&3, &3).unwrap().vertices, vec![3]);
+
+            // 5 has no incoming edges, and 6 has no outgoing ones
+            assert_eq!(all_pairs.distance(&1, &5), None);
+            assert_eq!(all_pairs.path(&6, &1), None);
+            assert_eq!(all_pairs.distances_from(&6).unwrap().len(), 1);
+            assert!(all_pairs.distances_from(&42).is_none());
+        }
+    }
+
+    #[test]
+    fn negative_cycle_is_err() {
+        let mut g = helper_make_graph();
+        // 2 -> 3 -> 4 -> 2 now costs -1 + 2 - 3 = -2
+        g.add_edge_with(&4, &2, -3).expect("Adding this edge should've worked!");
+
+        for result in [floyd_warshall(&g), johnson(&g)].iter() {
+            match result {
+                Err(ShortestPathError::NegativeCycle(cycle)) => {
+                    let total: i64 = (0..cycle.len())
+                        .map(|i| helper_cheapest_edge(&g, &cycle[i], &cycle[(i + 1) % cycle.len()]))
+                        .sum();
+                    assert!(total < 0);
+                }
+                _ => panic!("Expected a negative cycle!")
+            }
+        }
+    }
+
+    #[test]
+    fn negative_cycles_in_random_graphs() {
+        let mut rng = LFSR::new();
+        let n = 8;
+
+        for _ in 0..200 {
+            let edges: Vec<(i64, i64, i64)> = (0..(rng.rand() % 20))
+                .map(|_| ((rng.rand() % 8) as i64, (rng.rand() % 8) as i64, (rng.rand() % 12) as i64 - 3))
+                .collect();
+            let mut g: DiGraph<i64, i64> = DiGraph::new();
+            // Plus a source that reaches everything, so Bellman-Ford sees every cycle
+            let mut with_source: DiGraph<i64, i64> = DiGraph::new();
+            with_source.add_vertex(-1).expect("Adding a unique vertex should've worked!");
+            for v in 0..n {
+                g.add_vertex(v).expect("Adding a unique vertex should've worked!");
+                with_source.add_edge_with(&-1, &v, 0).expect("Adding this edge should've worked!");
+            }
+            for (u, v, weight) in edges.iter() {
+                g.add_edge_with(u, v, *weight).expect("Adding this edge should've worked!");
+                with_source.add_edge_with(u, v, *weight).expect("Adding this edge should've worked!");
+            }
+            let has_negative_cycle = bellman_ford(&with_source, &-1).is_err();
+
+            match floyd_warshall(&g) {
+                Err(ShortestPathError::NegativeCycle(cycle)) => {
+                    let total: i64 = (0..cycle.len())
+                        .map(|i| helper_cheapest_edge(&g, &cycle[i], &cycle[(i + 1) % cycle.len()]))
+                        .sum();
+                    assert!(total < 0);
+                    assert!(has_negative_cycle);
+                }
+                Ok(all_pairs) => {
+                    assert!(!has_negative_cycle);
+                    helper_check_against_bellman_ford(&g, &all_pairs);
+                }
+                Err(_) => panic!("Every vertex is in the graph!")
+            }
+        }
+    }
+
+    #[test]
+    fn float_rounding_cycle_is_err() {
+        // 0.3 + 0.6 - 0.9 rounds to just under zero, too little for
+        // Bellman-Ford to notice, so Floyd-Warshall has to find it on its own
+        let mut g: DiGraph<u8, f64> = DiGraph::new();
+        g.add_vertex(0).expect("Adding a unique vertex should've worked!");
+        g.add_edge_with(&0, &1, 0.3).expect("Adding this edge should've worked!");
+        g.add_edge_with(&1, &2, 0.6).expect("Adding this edge should've worked!");
+        g.add_edge_with(&2, &0, -0.9).expect("Adding this edge should've worked!");
+
+        match floyd_warshall(&g) {
+            Err(ShortestPathError::NegativeCycle(cycle)) => {
+                assert_eq!(cycle.len(), 3);
+                for i in 0..cycle.len() {
+                    assert!(g.are_neighbors(&cycle[i], &cycle[(i + 1) % cycle.len()]));
+                }
+            }
+            _ => panic!("Expected a negative cycle!")
+        }
+    }
+
+    #[test]
+    fn unsigned_and_float_weights() {
+        let mut g: DiGraph<char, u32> = DiGraph::new();
+        g.add_vertex('a').expect("Adding a unique vertex should've worked!");
+        g.add_edge_with(&'a', &'b', 3).expect("Adding this edge should've worked!");
+        g.add_edge_with(&'b', &'c', 4).expect("Adding this edge should've worked!");
+        g.add_edge_with(&'a', &'c', 10).expect("Adding this edge should've worked!");
+
+        assert_eq!(johnson(&g).unwrap().distance(&'a', &'c'), Some(7));
+        assert_eq!(floyd_warshall(&g).unwrap().distance(&'a', &'c'), Some(7));
+
+        let mut g: DiGraph<char, f64> = DiGraph::new();
+        g.add_vertex('a').expect("Adding a unique vertex should've worked!");
+        g.add_edge_with(&'a', &'b', 0.5).expect("Adding this edge should've worked!");
+        g.add_edge_with(&'b', &'c', -0.25).expect("Adding this edge should've worked!");
+        g.add_edge_with(&'a', &'c', 0.5).expect("Adding this edge should've worked!");
+
+        assert_eq!(johnson(&g).unwrap().distance(&'a', &'c'), Some(0.25));
+        assert_eq!(floyd_warshall(&g).unwrap().distance(&'a', &'c'), Some(0.25));
+    }
+}
@@ -0,0 +1,104 @@
+#[cfg(test)]
+mod assignment {
+    use dsa_in_rust::graphs::assignment::*;
+    use dsa_in_rust::misc::lfsr::LFSR;
+
+    /// Tries every way of giving the rows different columns
+    fn helper_brute_force(costs: &[Vec<i64>]) -> i64 {
+        fn go(costs: &[Vec<i64>], row: usize, used: &mut Vec<bool>) -> i64 {
+            if row == costs.len() {
+                return 0;
+            }
+            let mut best = i64::MAX;
+            for j in 0..used.len() {
+                if !used[j] {
+                    used[j] = true;
+                    best = best.min(costs[row][j] + go(costs, row + 1, used));
+                    used[j] = false;
+                }
+            }
+            best
+        }
+        go(costs, 0, &mut vec![false; costs[0].len()])
+    }
+
+    #[test]
+    fn square_matrix() {
+        let costs: Vec<Vec<u32>> = vec![
+            vec![4, 1, 3],
+            vec![2, 0, 5],
+            vec![3, 2, 2]
+        ];
+
+        let assignment = hungarian(&costs);
+        assert_eq!(assignment.pairs, vec![(0, 1), (1, 0), (2, 2)]);
+        assert_eq!(assignment.total_cost, 5);
+    }
+
+    #[test]
+    fn rectangular_matrices() {
+        // More jobs than workers, job 2 is left over
+        let costs: Vec<Vec<u32>> = vec![
+            vec![10, 19, 8, 15],
+            vec![10, 18, 7, 17],
+            vec![13, 16, 9, 14]
+        ];
+        let assignment = hungarian(&costs);
+        assert_eq!(assignment.pairs, vec![(0, 0), (1, 2), (2, 3)]);
+        assert_eq!(assignment.total_cost, 31);
+
+        // More workers than jobs, worker 1 is left over
+        let transposed: Vec<Vec<u32>> = (0..4).map(|j| costs.iter().map(|row| row[j]).collect()).collect();
+        let assignment = hungarian(&transposed);
+        assert_eq!(assignment.pairs, vec![(0, 0), (2, 1), (3, 2)]);
+        assert_eq!(assignment.total_cost, 31);
+    }
+
+    #[test]
+    fn negative_and_float_costs() {
+        // Maximizing a profit by negating it
+        let profits: Vec<Vec<i32>> = vec![vec![7, 3], vec![8, 1]];
+        let costs: Vec<Vec<i32>> = profits.iter().map(|row| row.iter().map(|p| -p).collect()).collect();
+        let assignment = hungarian(&costs);
+        assert_eq!(assignment.pairs, vec![(0, 1), (1, 0)]);
+        assert_eq!(assignment.total_cost, -11);
+
+        let costs: Vec<Vec<f64>> = vec![vec![0.5, 1.25], vec![0.75, 2.5]];
+        assert_eq!(hungarian(&costs).total_cost, 2.0);
+    }
+
+    #[test]
+    fn empty_matrices() {
+        let costs: Vec<Vec<u8>> = Vec::new();
+        let assignment = hungarian(&costs);
+        assert!(assignment.pairs.is_empty());
+        assert_eq!(assignment.total_cost, 0);
+
+        let costs: Vec<Vec<u8>> = vec![Vec::new(), Vec::new()];
+        assert!(hungarian(&costs).pairs.is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn ragged_matrix_panics() {
+        let costs: Vec<Vec<u8>> = vec![vec![1, 2], vec![3]];
+        hungarian(&costs);
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        let mut rng = LFSR::new();
+
+        for size in 1..=7 {
+            for extra in 0..3 {
+                let costs: Vec<Vec<i64>> = (0..size)
+                    .map(|_| (0..size + extra).map(|_| (rng.rand() % 100) as i64 - 20).collect())
+                    .collect();
+
+                let assignment = hungarian(&costs);
+                assert_eq!(assignment.pairs.len(), size);
+                assert_eq!(assignment.total_cost, helper_brute_force(&costs));
+            }
+        }
+    }
+}
@@ -0,0 +1,123 @@
+#[cfg(test)]
+mod bellman_ford {
+    use dsa_in_rust::graphs::bellman_ford::*;
+    use dsa_in_rust::graphs::digraph::DiGraph;
+    use dsa_in_rust::graphs::dijkstra::dijkstra;
+    use dsa_in_rust::graphs::error::GraphError;
+    use dsa_in_rust::graphs::shortest_paths::ShortestPathError;
+
+    fn helper_make_graph() -> DiGraph<char, i64> {
+        // A small graph with a couple of "rebates" but no negative cycles
+        let mut g: DiGraph<char, i64> = DiGraph::new();
+
+        g.add_vertex('s').expect("Adding a unique vertex should've worked!");
+        g.add_edge_with(&'s', &'a', 4).expect("Adding this edge should've worked!");
+        g.add_edge_with(&'s', &'b', 5).expect("Adding this edge should've worked!");
+        g.add_edge_with(&'a', &'c', 3).expect("Adding this edge should've worked!");
+        g.add_edge_with(&'b', &'a', -3).expect("Adding this edge should've worked!");
+        g.add_edge_with(&'c', &'d', 2).expect("Adding this edge should've worked!");
+        g.add_edge_with(&'b', &'d', 8).expect("Adding this edge should've worked!");
+        g.add_edge_with(&'d', &'c', -1).expect("Adding this edge should've worked!");
+
+        g
+    }
+
+    fn helper_is_cycle(g: &DiGraph<char, i64>, cycle: &[char]) -> bool {
+        !cycle.is_empty() && (0..cycle.len()).all(|i| {
+            g.are_neighbors(&cycle[i], &cycle[(i + 1) % cycle.len()])
+        })
+    }
+
+    #[test]
+    fn handles_negative_edges() {
+        let g = helper_make_graph();
+
+        let paths = bellman_ford(&g, &'s').expect("There are no negative cycles!");
+        assert_eq!(paths.distance_to(&'s'), Some(0));
+        assert_eq!(paths.distance_to(&'a'), Some(2));
+        assert_eq!(paths.distance_to(&'b'), Some(5));
+        assert_eq!(paths.distance_to(&'c'), Some(5));
+        assert_eq!(paths.distance_to(&'d'), Some(7));
+        assert_eq!(paths.path_to(&'d').unwrap().vertices, vec!['s', 'b', 'a', 'c', 'd']);
+
+        // Dijkstra's algorithm refuses this graph outright
+        assert_eq!(dijkstra(&g, &'s').err(), Some(GraphError::NegativeWeight));
+    }
+
+    #[test]
+    fn agrees_with_dijkstra_on_non_negative_weights() {
+        let mut g: DiGraph<i64, i64> = DiGraph::new();
+
+        g.add_vertex(0).expect("Adding a unique vertex should've worked!");
+        for i in 0..20 {
+            g.add_edge_with(&i, &(i + 1), (i * 7) % 5 + 1).expect("Adding this edge should've worked!");
+            g.add_edge_with(&i, &((i * 3) % 21), (i * 11) % 13).expect("Adding this edge should've worked!");
+        }
+        g.add_vertex(100).expect("Adding a unique vertex should've worked!");
+
+        let expected = dijkstra(&g, &0).expect("All weights are non-negative!");
+        let actual = bellman_ford(&g, &0).expect("There are no negative cycles!");
+        for v in g.vertices() {
+            assert_eq!(actual.distance_to(v), expected.distance_to(v));
+        }
+        assert_eq!(actual.unreachable(), &[100]);
+    }
+
+    #[test]
+    fn reachable_negative_cycle_is_err() {
+        let mut g = helper_make_graph();
+        // c -> d -> e -> c costs 2 + 1 - 4 = -1
+        g.add_edge_with(&'d', &'e', 1).expect("Adding this edge should've worked!");
+        g.add_edge_with(&'e', &'c', -4).expect("Adding this edge should've worked!");
+
+        match bellman_ford(&g, &'s') {
+            Err(ShortestPathError::NegativeCycle(cycle)) => {
+                assert!(helper_is_cycle(&g, &cycle));
+                let mut sorted = cycle.clone();
+                sorted.sort();
+                assert!(sorted == vec!['c', 'd'] || sorted == vec!['c', 'd', 'e']);
+                let total: i64 = (0..cycle.len())
+                    .map(|i| *g.edge_weight(&cycle[i], &cycle[(i + 1) % cycle.len()]).unwrap())
+                    .sum();
+                assert!(total < 0);
+            }
+            other => panic!("Expected a negative cycle, got {:?}", other.map(|_| ()))
+        }
+    }
+
+    #[test]
+    fn negative_self_loop_is_a_cycle() {
+        let mut g: DiGraph<char, i64> = DiGraph::new();
+        g.add_vertex('a').expect("Adding a unique vertex should've worked!");
+        g.add_edge_with(&'a', &'a', -1).expect("Adding this edge should've worked!");
+
+        let err = bellman_ford(&g, &'a').expect_err("This graph has a negative cycle!");
+        assert_eq!(err, ShortestPathError::NegativeCycle(vec!['a']));
+        assert_eq!(err.to_string(), "Graph contains a negative cycle: 'a' -> 'a'");
+        assert_eq!(GraphError::from(err), GraphError::NegativeCycle);
+    }
+
+    #[test]
+    fn unreachable_negative_cycle_is_ignored() {
+        let mut g = helper_make_graph();
+        g.add_vertex('x').expect("Adding a unique vertex should've worked!");
+        g.add_edge_with(&'x', &'y', -5).expect("Adding this edge should've worked!");
+        g.add_edge_with(&'y', &'x', 1).expect("Adding this edge should've worked!");
+        g.add_edge_with(&'x', &'s', 1).expect("Adding this edge should've worked!");
+
+        let paths = bellman_ford(&g, &'s').expect("The negative cycle can't be reached!");
+        assert_eq!(paths.distance_to(&'d'), Some(7));
+        let mut unreachable = paths.unreachable().to_vec();
+        unreachable.sort();
+        assert_eq!(unreachable, vec!['x', 'y']);
+    }
+
+    #[test]
+    fn missing_source_is_err() {
+        let g = helper_make_graph();
+        assert_eq!(
+            bellman_ford(&g, &'z').err(),
+            Some(ShortestPathError::Graph(GraphError::MissingSourceVertex))
+        );
+    }
+}
@@ -0,0 +1,149 @@
+#[cfg(test)]
+mod biconnected {
+    use dsa_in_rust::graphs::biconnected::*;
+    use dsa_in_rust::graphs::graph::Graph;
+    use dsa_in_rust::misc::lfsr::LFSR;
+
+    fn helper_make_graph() -> Graph<i64> {
+        // Two triangles 1-2-3 and 3-4-5 sharing vertex 3, a bridge 5-6,
+        // a square 6-7-8-9, and a dangling 9-10
+        let mut g: Graph<i64> = Graph::new();
+
+        g.add_vertex(1).expect("Adding a unique vertex should've worked!");
+        let edges = [(1, 2), (2, 3), (3, 1), (3, 4), (4, 5), (5, 3), (5, 6),
+                     (6, 7), (7, 8), (8, 9), (9, 6), (9, 10)];
+        for (u, v) in edges.iter() {
+            g.add_edge(u, v).expect("Adding this edge should've worked!");
+        }
+
+        g
+    }
+
+    fn helper_sorted_bridges(g: &Graph<i64>) -> Vec<(i64, i64)> {
+        let mut found: Vec<(i64, i64)> = bridges(g).into_iter().map(|(u, v)| (u.min(v), u.max(v))).collect();
+        found.sort();
+        found
+    }
+
+    #[test]
+    fn articulation_points_are_found() {
+        let g = helper_make_graph();
+        assert_eq!(articulation_points(&g), vec![3, 5, 6, 9]);
+    }
+
+    #[test]
+    fn bridges_are_found() {
+        let g = helper_make_graph();
+        assert_eq!(helper_sorted_bridges(&g), vec![(5, 6), (9, 10)]);
+    }
+
+    #[test]
+    fn biconnected_components_are_found() {
+        let g = helper_make_graph();
+
+        let mut components = biconnected_components(&g);
+        components.sort();
+        assert_eq!(components, vec![vec![1, 2, 3], vec![3, 4, 5], vec![5, 6], vec![6, 7, 8, 9], vec![9, 10]]);
+    }
+
+    #[test]
+    fn parallel_edges_and_self_loops() {
+        let mut g: Graph<i64> = Graph::new();
+        g.add_vertex(1).expect("Adding a unique vertex should've worked!");
+        g.add_edge(&1, &2).expect("Adding this edge should've worked!");
+        g.add_edge(&2, &3).expect("Adding this edge should've worked!");
+        g.add_edge(&3, &3).expect("Adding this edge should've worked!");
+        assert_eq!(helper_sorted_bridges(&g), vec![(1, 2), (2, 3)]);
+        assert_eq!(articulation_points(&g), vec![2]);
+
+        // A second copy of an edge means losing one isn't a problem
+        g.add_edge(&2, &1).expect("Adding this edge should've worked!");
+        assert_eq!(helper_sorted_bridges(&g), vec![(2, 3)]);
+        assert_eq!(articulation_points(&g), vec![2]);
+        assert_eq!(biconnected_components(&g).len(), 2);
+    }
+
+    #[test]
+    fn no_edges_no_components() {
+        let mut g: Graph<i64> = Graph::new();
+        assert!(biconnected_components(&g).is_empty());
+
+        g.add_vertex(1).expect("Adding a unique vertex should've worked!");
+        g.add_edge(&1, &1).expect("Adding this edge should've worked!");
+        assert!(articulation_points(&g).is_empty());
+        assert!(bridges(&g).is_empty());
+        assert!(biconnected_components(&g).is_empty());
+    }
+
+    #[test]
+    fn long_path_does_not_overflow_stack() {
+        let mut g: Graph<i64> = Graph::new();
+        let n = 50_000;
+
+        g.add_vertex(0).expect("Adding a unique vertex should've worked!");
+        for v in 1..n {
+            g.add_edge(&(v - 1), &v).expect("Adding this edge should've worked!");
+        }
+        assert_eq!(articulation_points(&g).len(), n as usize - 2);
+        assert_eq!(bridges(&g).len(), n as usize - 1);
+
+        g.add_edge(&(n - 1), &0).expect("Adding this edge should've worked!");
+        assert!(articulation_points(&g).is_empty());
+        assert_eq!(biconnected_components(&g).len(), 1);
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        let mut rng = LFSR::new();
+        let n = 30;
+
+        for _ in 0..20 {
+            let mut g: Graph<i64> = Graph::new();
+            for v in 0..n {
+                g.add_vertex(v).expect("Adding a unique vertex should've worked!");
+            }
+            for _ in 0..35 {
+                let u = (rng.rand() % n as u32) as i64;
+                let v = (rng.rand() % n as u32) as i64;
+                g.add_edge(&u, &v).expect("Adding this edge should've worked!");
+            }
+            let num_components = g.connected_components().len();
+
+            let mut expected_points = Vec::new();
+            for v in 0..n {
+                let mut without = Graph::new();
+                for u in 0..n {
+                    without.add_vertex(u).expect("Adding a unique vertex should've worked!");
+                }
+                for u in 0..n {
+                    for w in g.neighbors_of(&u).unwrap() {
+                        if u <= *w && u != v && *w != v {
+                            without.add_edge(&u, w).expect("Adding this edge should've worked!");
+                        }
+                    }
+                }
+                // v itself is left behind as a component of its own
+                if without.connected_components().len() > num_components + 1 {
+                    expected_points.push(v);
+                }
+            }
+            assert_eq!(articulation_points(&g), expected_points);
+
+            for (u, v) in bridges(&g) {
+                let mut without = Graph::new();
+                for x in 0..n {
+                    without.add_vertex(x).expect("Adding a unique vertex should've worked!");
+                }
+                for x in 0..n {
+                    for y in g.neighbors_of(&x).unwrap() {
+                        if x <= *y {
+                            without.add_edge(&x, y).expect("Adding this edge should've worked!");
+                        }
+                    }
+                }
+                without.remove_edge(&u, &v);
+                assert_eq!(without.connected_components().len(), num_components + 1);
+            }
+        }
+    }
+}
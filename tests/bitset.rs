@@ -0,0 +1,79 @@
+#![allow(clippy::bool_assert_comparison)]
+
+#[cfg(test)]
+mod bitset {
+    use dsa_in_rust::misc::bitset::*;
+
+    #[test]
+    fn new_set_is_empty() {
+        let s = BitSet::new(100);
+        assert_eq!(s.capacity(), 100);
+        assert_eq!(s.len(), 0);
+        assert_eq!(s.is_empty(), true);
+        assert_eq!(s.contains(0), false);
+        assert_eq!(s.contains(1000), false);
+        assert_eq!(s.iter().next(), None);
+    }
+
+    #[test]
+    fn insert_and_remove() {
+        let mut s = BitSet::new(130);
+
+        assert_eq!(s.insert(3), true);
+        assert_eq!(s.insert(3), false);
+        assert_eq!(s.insert(64), true);
+        assert_eq!(s.insert(129), true);
+        assert_eq!(s.len(), 3);
+        assert_eq!(s.contains(64), true);
+        assert_eq!(s.contains(63), false);
+
+        assert_eq!(s.remove(64), true);
+        assert_eq!(s.remove(64), false);
+        assert_eq!(s.remove(1000), false);
+        assert_eq!(s.iter().collect::<Vec<usize>>(), vec![3, 129]);
+
+        s.clear();
+        assert_eq!(s.is_empty(), true);
+    }
+
+    #[test]
+    #[should_panic]
+    fn insert_out_of_bounds_panics() {
+        let mut s = BitSet::new(10);
+        s.insert(10);
+    }
+
+    #[test]
+    fn union_merges_sets() {
+        let mut a = BitSet::new(200);
+        let mut b = BitSet::new(200);
+        for i in (0..200).step_by(3) {
+            a.insert(i);
+        }
+        for i in (0..200).step_by(5) {
+            b.insert(i);
+        }
+
+        a.union_with(&b);
+        let expected: Vec<usize> = (0..200).filter(|i| i % 3 == 0 || i % 5 == 0).collect();
+        assert_eq!(a.iter().collect::<Vec<usize>>(), expected);
+        assert_eq!(a.len(), expected.len());
+    }
+
+    #[test]
+    fn grow_keeps_elements() {
+        let mut s = BitSet::new(60);
+        s.insert(5);
+        s.insert(59);
+
+        s.grow(130);
+        assert_eq!(s.capacity(), 130);
+        assert_eq!(s.insert(129), true);
+        assert_eq!(s.iter().collect::<Vec<usize>>(), vec![5, 59, 129]);
+
+        // Never shrinks
+        s.grow(10);
+        assert_eq!(s.capacity(), 130);
+        assert_eq!(s.len(), 3);
+    }
+}
@@ -0,0 +1,249 @@
+#[cfg(test)]
+mod csr {
+    use dsa_in_rust::graphs::a_star::a_star;
+    use dsa_in_rust::graphs::all_pairs_shortest_paths::{floyd_warshall, johnson};
+    use dsa_in_rust::graphs::bellman_ford::bellman_ford;
+    use dsa_in_rust::graphs::csr::CsrGraph;
+    use dsa_in_rust::graphs::digraph::DiGraph;
+    use dsa_in_rust::graphs::dijkstra::{dijkstra, dijkstra_to};
+    use dsa_in_rust::graphs::dominators::dominators;
+    use dsa_in_rust::graphs::error::GraphError;
+    use dsa_in_rust::graphs::max_flow::{dinic, edmonds_karp};
+    use dsa_in_rust::graphs::reachability::ReachabilityIndex;
+    use dsa_in_rust::graphs::shortest_paths::ShortestPathError;
+    use dsa_in_rust::graphs::strongly_connected::strongly_connected_components;
+    use dsa_in_rust::graphs::topological_sorting::insertion_order_topological_sort;
+    use dsa_in_rust::graphs::transitive::transitive_closure;
+    use dsa_in_rust::graphs::traversal::*;
+    use dsa_in_rust::misc::lfsr::LFSR;
+    use std::rc::Rc;
+
+    fn helper_make_graph() -> DiGraph<&'static str, i32> {
+        let mut g: DiGraph<&'static str, i32> = DiGraph::new();
+        for v in ["s", "a", "b", "c", "d", "lonely"] {
+            g.add_vertex(v).expect("Adding a unique vertex should've worked!");
+        }
+        let edges = [("s", "a", 4), ("s", "b", 1), ("b", "a", 2), ("a", "c", 1),
+                     ("b", "c", 5), ("c", "d", 3), ("d", "b", -2)];
+        for (u, v, w) in edges.iter() {
+            g.add_edge_with(u, v, *w).expect("Adding this edge should've worked!");
+        }
+        g
+    }
+
+    #[test]
+    fn vertices_get_indices_in_insertion_order() {
+        let g = helper_make_graph();
+        let csr = CsrGraph::from_digraph(&g);
+
+        assert_eq!(csr.num_vertices(), 6);
+        assert_eq!(csr.num_edges(), 7);
+        assert_eq!(csr.vertices().copied().collect::<Vec<_>>(), vec!["s", "a", "b", "c", "d", "lonely"]);
+        assert_eq!(csr.index_of(&"s"), Some(0));
+        assert_eq!(csr.index_of(&"d"), Some(4));
+        assert_eq!(csr.index_of(&"nope"), None);
+        assert_eq!(csr.vertex(2), Some(&"b"));
+        assert_eq!(csr.vertex(6), None);
+        assert!(csr.contains(&"lonely"));
+        assert!(!csr.contains(&"nope"));
+    }
+
+    #[test]
+    fn vertices_are_stored_once() {
+        let mut g: DiGraph<Rc<str>> = DiGraph::new();
+        let a: Rc<str> = Rc::from("a");
+        let b: Rc<str> = Rc::from("b");
+        g.add_vertex(Rc::clone(&a)).expect("Adding a unique vertex should've worked!");
+        g.add_edge(&a, &b).expect("Adding this edge should've worked!");
+        g.add_edge(&b, &a).expect("Adding this edge should've worked!");
+        let before = (Rc::strong_count(&a), Rc::strong_count(&b));
+
+        let csr = CsrGraph::from_digraph(&g);
+        assert_eq!(Rc::strong_count(&a), before.0 + 1);
+        assert_eq!(Rc::strong_count(&b), before.1 + 1);
+        assert_eq!(csr.index_of(&b), Some(1));
+    }
+
+    #[test]
+    fn edges_keep_their_order_and_payloads() {
+        let g = helper_make_graph();
+        let csr: CsrGraph<&str, i32> = (&g).into();
+
+        assert_eq!(csr.neighbor_indices(0), &[1, 2]);
+        assert_eq!(csr.neighbor_indices(5), &[] as &[u32]);
+        assert_eq!(csr.neighbors_of(&"b").unwrap().collect::<Vec<_>>(), vec![&"a", &"c"]);
+        assert_eq!(csr.edges_of(&"d").unwrap().collect::<Vec<_>>(), vec![(&"b", &-2)]);
+        assert!(csr.neighbors_of(&"nope").is_none());
+        assert!(csr.edges_of(&"nope").is_none());
+
+        assert_eq!(csr.out_degree(&"s"), Some(2));
+        assert_eq!(csr.out_degree(&"lonely"), Some(0));
+        assert_eq!(csr.out_degree(&"nope"), None);
+        assert!(csr.are_neighbors(&"c", &"d"));
+        assert!(!csr.are_neighbors(&"d", &"c"));
+        assert!(!csr.are_neighbors(&"nope", &"c"));
+        assert_eq!(csr.edge_weight(&"s", &"b"), Some(&1));
+        assert_eq!(csr.edge_weight(&"b", &"s"), None);
+    }
+
+    #[test]
+    fn predecessors_come_out_in_vertex_order() {
+        let g = helper_make_graph();
+        let csr = CsrGraph::from_digraph(&g);
+
+        assert_eq!(csr.predecessor_indices(1), &[0, 2]);
+        assert_eq!(csr.predecessors_of(&"b").unwrap().collect::<Vec<_>>(), vec![&"s", &"d"]);
+        assert_eq!(csr.predecessors_of(&"s").unwrap().count(), 0);
+        assert!(csr.predecessors_of(&"nope").is_none());
+        assert_eq!(csr.in_degree(&"c"), Some(2));
+        assert_eq!(csr.in_degree(&"lonely"), Some(0));
+        assert_eq!(csr.in_degree(&"nope"), None);
+    }
+
+    #[test]
+    fn parallel_edges_and_self_loops_are_kept() {
+        let mut g: DiGraph<u8, u8> = DiGraph::new();
+        g.add_vertex(0).expect("Adding a unique vertex should've worked!");
+        g.add_vertex(1).expect("Adding a unique vertex should've worked!");
+        g.add_edge_with(&0, &1, 5).expect("Adding this edge should've worked!");
+        g.add_edge_with(&0, &1, 3).expect("Adding this edge should've worked!");
+        g.add_edge_with(&1, &1, 7).expect("Adding this edge should've worked!");
+
+        let csr = CsrGraph::from_digraph(&g);
+        assert_eq!(csr.num_edges(), 3);
+        assert_eq!(csr.neighbor_indices(0), &[1, 1]);
+        assert_eq!(csr.predecessor_indices(1), &[0, 0, 1]);
+        assert_eq!(csr.edge_weight(&0, &1), Some(&5));
+        assert_eq!(csr.edge_weight(&1, &1), Some(&7));
+    }
+
+    #[test]
+    fn traversals_match_the_digraph() {
+        let g = helper_make_graph();
+        let csr = CsrGraph::from_digraph(&g);
+
+        let expected: Vec<_> = Bfs::new(&g, &"s").unwrap().collect();
+        assert_eq!(Bfs::new(&csr, &"s").unwrap().collect::<Vec<_>>(), expected);
+        let expected: Vec<_> = Dfs::full(&g).collect();
+        assert_eq!(Dfs::full(&csr).collect::<Vec<_>>(), expected);
+        assert_eq!(Dfs::new(&csr, &"nope").err(), Some(GraphError::MissingVertex));
+
+        let mut expected = Vec::new();
+        depth_first_visit_all(&g, |event| expected.push(event));
+        let mut events = Vec::new();
+        depth_first_visit_all(&csr, |event| events.push(event));
+        assert_eq!(events, expected);
+    }
+
+    #[test]
+    fn shortest_paths_match_the_digraph() {
+        let g = helper_make_graph();
+        let csr = CsrGraph::from_digraph(&g);
+
+        let paths = bellman_ford(&csr, &"s").expect("There are no negative cycles!");
+        assert_eq!(paths.distance_to(&"d"), Some(7));
+        assert_eq!(paths.path_to(&"b").unwrap().vertices, vec!["s", "b"]);
+        assert_eq!(paths.unreachable(), &["lonely"]);
+
+        let floyd = floyd_warshall(&csr).expect("There are no negative cycles!");
+        let john = johnson(&csr).expect("There are no negative cycles!");
+        for u in g.vertices() {
+            for v in g.vertices() {
+                assert_eq!(floyd.distance(u, v), john.distance(u, v));
+            }
+            let from_u = bellman_ford(&g, u).unwrap();
+            for v in g.vertices() {
+                assert_eq!(floyd.distance(u, v), from_u.distance_to(v));
+            }
+        }
+
+        let mut g = helper_make_graph();
+        g.add_edge_with(&"d", &"c", -4).expect("Adding this edge should've worked!");
+        let csr = CsrGraph::from_digraph(&g);
+        match bellman_ford(&csr, &"s") {
+            Err(ShortestPathError::NegativeCycle(cycle)) => assert_eq!(cycle.len(), 2),
+            _ => panic!("The cycle c -> d -> c is negative!")
+        }
+    }
+
+    #[test]
+    fn dijkstra_and_a_star_work_on_csr() {
+        let mut rng = LFSR::new();
+        let n = 40;
+
+        for _ in 0..5 {
+            let mut g: DiGraph<u32, u32> = DiGraph::new();
+            for v in 0..n {
+                g.add_vertex(v).expect("Adding a unique vertex should've worked!");
+            }
+            for _ in 0..(3 * n) {
+                let u = rng.rand() % n;
+                let v = rng.rand() % n;
+                g.add_edge_with(&u, &v, rng.rand() % 20).expect("Adding this edge should've worked!");
+            }
+            let csr = CsrGraph::from_digraph(&g);
+
+            let expected = dijkstra(&g, &0).unwrap();
+            let paths = dijkstra(&csr, &0).unwrap();
+            for v in 0..n {
+                assert_eq!(paths.distance_to(&v), expected.distance_to(&v));
+                let to_v = dijkstra_to(&csr, &0, &v).unwrap();
+                assert_eq!(to_v.map(|path| path.cost), expected.distance_to(&v));
+                let guided = a_star(&csr, &0, |w| *w == v, |_, _, w| *w, |_| 0).unwrap();
+                assert_eq!(guided.map(|path| path.cost), expected.distance_to(&v));
+            }
+        }
+    }
+
+    #[test]
+    fn graph_algorithms_match_the_digraph() {
+        let mut rng = LFSR::new();
+        let n = 30;
+
+        for _ in 0..5 {
+            let mut g: DiGraph<u32, u32> = DiGraph::new();
+            for v in 0..n {
+                g.add_vertex(v).expect("Adding a unique vertex should've worked!");
+            }
+            for _ in 0..(2 * n) {
+                let u = rng.rand() % n;
+                let v = rng.rand() % n;
+                g.add_edge_with(&u, &v, rng.rand() % 10).expect("Adding this edge should've worked!");
+            }
+            let csr = CsrGraph::from_digraph(&g);
+
+            let sorted = |components: Vec<Vec<u32>>| {
+                let mut components: Vec<Vec<u32>> = components.into_iter()
+                    .map(|mut c| { c.sort(); c })
+                    .collect();
+                components.sort();
+                components
+            };
+            assert_eq!(sorted(strongly_connected_components(&csr)), sorted(strongly_connected_components(&g)));
+            assert_eq!(
+                insertion_order_topological_sort(&csr).map_err(|err| err.components),
+                insertion_order_topological_sort(&g).map_err(|err| err.components)
+            );
+
+            let closure = transitive_closure(&csr);
+            let index = ReachabilityIndex::new(&csr);
+            let expected = transitive_closure(&g);
+            for u in 0..n {
+                for v in 0..n {
+                    assert_eq!(closure.are_neighbors(&u, &v), expected.are_neighbors(&u, &v));
+                    assert_eq!(index.reaches(&u, &v), u == v || expected.are_neighbors(&u, &v));
+                }
+            }
+
+            let doms = dominators(&csr, &0).unwrap();
+            let expected = dominators(&g, &0).unwrap();
+            for v in 0..n {
+                assert_eq!(doms.immediate_dominator(&v), expected.immediate_dominator(&v));
+            }
+
+            let flow = edmonds_karp(&g, &0, &(n - 1)).unwrap().value;
+            assert_eq!(edmonds_karp(&csr, &0, &(n - 1)).unwrap().value, flow);
+            assert_eq!(dinic(&csr, &0, &(n - 1)).unwrap().value, flow);
+        }
+    }
+}
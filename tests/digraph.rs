@@ -1,6 +1,9 @@
+#![allow(clippy::bool_assert_comparison)]
+
 #[cfg(test)]
 mod digraph {
     use dsa_in_rust::graphs::digraph::*;
+    use dsa_in_rust::graphs::error::GraphError;
 
     #[test]
     fn new_graph_has_no_edges_or_vertices() {
@@ -49,10 +52,23 @@ mod digraph {
 
         g.add_vertex(1).expect("Inserting a unique vertex should've worked");
 
-        assert!(g.add_vertex(1).is_err());
+        assert_eq!(g.add_vertex(1), Err(GraphError::DuplicateVertex));
         assert!(g.add_vertex(2).is_ok());
     }
 
+    #[test]
+    fn graph_errors_work_as_std_errors() {
+        fn build() -> Result<DiGraph<i64>, Box<dyn std::error::Error>> {
+            let mut g: DiGraph<i64> = DiGraph::new();
+            g.add_vertex(1)?;
+            g.add_edge(&2, &1)?;
+            Ok(g)
+        }
+
+        let err = build().err().expect("Adding an edge from a missing vertex should fail!");
+        assert_eq!(err.to_string(), GraphError::MissingSourceVertex.to_string());
+    }
+
     #[test]
     fn removing_nonexistent_vertex_idempotent() {
         let mut g: DiGraph<i64> = DiGraph::new();
@@ -167,7 +183,7 @@ mod digraph {
     fn adding_edge_with_bad_source_is_err() {
         let mut g: DiGraph<i64> = DiGraph::new();
 
-        assert!(g.add_edge(&1, &2).is_err());
+        assert_eq!(g.add_edge(&1, &2), Err(GraphError::MissingSourceVertex));
     }
 
     fn helper_make_graph() -> DiGraph<i64> {
@@ -291,4 +307,145 @@ mod digraph {
         assert_eq!(g.get_source_vertices().len(), 1);
         assert_eq!(g.get_source_vertices().contains(&2), true);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn predecessors_iterator_should_return_correct_nodes() {
+        let g: DiGraph<i64> = helper_make_graph();
+
+        assert_eq!(g.predecessors_of(&1).unwrap().eq([3].iter()), true);
+        assert_eq!(g.predecessors_of(&4).unwrap().eq([2, 5].iter()), true);
+        assert_eq!(g.predecessors_of(&6).unwrap().eq([5, 4].iter()), true);
+        assert_eq!(g.predecessors_of(&42).is_none(), true);
+    }
+
+    #[test]
+    fn predecessors_follow_edge_removal() {
+        let mut g: DiGraph<i64> = helper_make_graph();
+
+        g.remove_edge(&5, &4);
+        assert_eq!(g.predecessors_of(&4).unwrap().eq([2].iter()), true);
+
+        // Removing an edge that doesn't exist shouldn't touch anything
+        g.remove_edge(&6, &4);
+        assert_eq!(g.predecessors_of(&4).unwrap().eq([2].iter()), true);
+
+        g.remove_vertex(&2);
+        assert_eq!(g.predecessors_of(&4).unwrap().eq([].iter()), true);
+        assert_eq!(g.predecessors_of(&3).unwrap().eq([4].iter()), true);
+        assert_eq!(g.predecessors_of(&5).unwrap().eq([1].iter()), true);
+    }
+
+    #[test]
+    fn remove_vertex_with_self_loop_and_parallel_edges() {
+        let mut g: DiGraph<i64> = DiGraph::new();
+
+        g.add_vertex(1).expect("Inserting a unique vertex should've worked!");
+        g.add_edge(&1, &1).expect("Source vertex SHOULD exist here!");
+        g.add_edge(&1, &2).expect("Source vertex SHOULD exist here!");
+        g.add_edge(&1, &2).expect("Source vertex SHOULD exist here!");
+        g.add_edge(&2, &1).expect("Source vertex SHOULD exist here!");
+        g.add_edge(&2, &1).expect("Source vertex SHOULD exist here!");
+
+        assert_eq!(g.in_degree(&1).unwrap(), 3);
+        assert_eq!(g.in_degree(&2).unwrap(), 2);
+
+        g.remove_vertex(&1);
+        assert_eq!(g.num_vertices(), 1);
+        assert_eq!(g.num_edges(), 0);
+        assert_eq!(g.in_degree(&2).unwrap(), 0);
+        assert_eq!(g.out_degree(&2).unwrap(), 0);
+    }
+
+    #[test]
+    fn insertion_rank_follows_insertion_order() {
+        let mut g: DiGraph<i64> = DiGraph::new();
+
+        g.add_vertex(3).expect("Inserting a unique vertex should've worked!");
+        g.add_vertex(1).expect("Inserting a unique vertex should've worked!");
+        // Implicitly created vertices are ranked too
+        g.add_edge(&1, &2).expect("Source vertex SHOULD exist here!");
+
+        assert!(g.insertion_rank(&3).unwrap() < g.insertion_rank(&1).unwrap());
+        assert!(g.insertion_rank(&1).unwrap() < g.insertion_rank(&2).unwrap());
+        assert_eq!(g.insertion_rank(&42), None);
+
+        // Re-adding a vertex sends it to the back of the line
+        g.remove_vertex(&3);
+        assert_eq!(g.insertion_rank(&3), None);
+        g.add_vertex(3).expect("Inserting a unique vertex should've worked!");
+        assert!(g.insertion_rank(&2).unwrap() < g.insertion_rank(&3).unwrap());
+        assert_eq!(g.vertices_in_insertion_order(), vec![&1, &2, &3]);
+    }
+
+    fn helper_make_weighted_graph() -> DiGraph<&'static str, u32> {
+        let mut g: DiGraph<&'static str, u32> = DiGraph::new();
+
+        g.add_vertex("a").expect("Inserting a unique vertex should've worked!");
+        g.add_edge_with(&"a", &"b", 7).expect("Source vertex SHOULD exist here!");
+        g.add_edge_with(&"a", &"c", 9).expect("Source vertex SHOULD exist here!");
+        g.add_edge_with(&"b", &"c", 10).expect("Source vertex SHOULD exist here!");
+        g.add_edge_with(&"c", &"a", 2).expect("Source vertex SHOULD exist here!");
+
+        g
+    }
+
+    #[test]
+    fn weighted_edges_keep_their_payloads() {
+        let g = helper_make_weighted_graph();
+
+        assert_eq!(g.num_vertices(), 3);
+        assert_eq!(g.num_edges(), 4);
+        assert_eq!(g.edge_weight(&"a", &"b"), Some(&7));
+        assert_eq!(g.edge_weight(&"a", &"c"), Some(&9));
+        assert_eq!(g.edge_weight(&"c", &"a"), Some(&2));
+        assert_eq!(g.edge_weight(&"b", &"a"), None);
+        assert_eq!(g.edge_weight(&"z", &"a"), None);
+
+        let edges: Vec<(&str, u32)> = g.edges_of(&"a").unwrap().map(|(v, w)| (*v, *w)).collect();
+        assert_eq!(edges, vec![("b", 7), ("c", 9)]);
+
+        // The plain neighbor queries ignore the payloads
+        assert_eq!(g.neighbors_of(&"a").unwrap().eq(["b", "c"].iter()), true);
+        assert_eq!(g.are_neighbors(&"b", &"c"), true);
+        assert_eq!(g.in_degree(&"c").unwrap(), 2);
+    }
+
+    #[test]
+    fn adding_weighted_edge_with_bad_source_is_err() {
+        let mut g: DiGraph<i64, f64> = DiGraph::new();
+
+        assert_eq!(g.add_edge_with(&1, &2, 0.5), Err(GraphError::MissingSourceVertex));
+        assert_eq!(g.num_vertices(), 0);
+    }
+
+    #[test]
+    fn removing_weighted_edges_and_vertices() {
+        let mut g = helper_make_weighted_graph();
+
+        g.remove_edge(&"a", &"c");
+        assert_eq!(g.edge_weight(&"a", &"c"), None);
+        assert_eq!(g.in_degree(&"c").unwrap(), 1);
+
+        g.remove_vertex(&"b");
+        assert_eq!(g.num_edges(), 1);
+        assert_eq!(g.edges_of(&"a").unwrap().count(), 0);
+        assert_eq!(g.edge_weight(&"c", &"a"), Some(&2));
+    }
+
+    #[test]
+    fn parallel_weighted_edges_are_distinct() {
+        let mut g: DiGraph<i64, &'static str> = DiGraph::new();
+
+        g.add_vertex(1).expect("Inserting a unique vertex should've worked!");
+        g.add_edge_with(&1, &2, "first").expect("Source vertex SHOULD exist here!");
+        g.add_edge_with(&1, &2, "second").expect("Source vertex SHOULD exist here!");
+
+        assert_eq!(g.out_degree(&1).unwrap(), 2);
+        assert_eq!(g.edge_weight(&1, &2), Some(&"first"));
+
+        // The oldest edge goes first
+        g.remove_edge(&1, &2);
+        assert_eq!(g.edge_weight(&1, &2), Some(&"second"));
+        assert_eq!(g.in_degree(&2).unwrap(), 1);
+    }
+}
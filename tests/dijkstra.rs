@@ -0,0 +1,115 @@
+#[cfg(test)]
+mod dijkstra {
+    use dsa_in_rust::graphs::digraph::DiGraph;
+    use dsa_in_rust::graphs::dijkstra::*;
+    use dsa_in_rust::graphs::error::GraphError;
+
+    fn helper_make_graph() -> DiGraph<i64, u32> {
+        // See: https://en.wikipedia.org/wiki/Dijkstra%27s_algorithm
+        // The example is undirected, so every edge goes both ways.
+        let mut g: DiGraph<i64, u32> = DiGraph::new();
+
+        for v in 1..=6 {
+            g.add_vertex(v).expect("Adding a unique vertex should've worked!");
+        }
+        let edges = [(1, 2, 7), (1, 3, 9), (1, 6, 14), (2, 3, 10), (2, 4, 15),
+                     (3, 4, 11), (3, 6, 2), (4, 5, 6), (5, 6, 9)];
+        for (u, v, w) in edges.iter() {
+            g.add_edge_with(u, v, *w).expect("Adding this edge should've worked!");
+            g.add_edge_with(v, u, *w).expect("Adding this edge should've worked!");
+        }
+
+        g
+    }
+
+    #[test]
+    fn distances_of_wikipedia_example() {
+        let g = helper_make_graph();
+
+        let paths = dijkstra(&g, &1).expect("All weights are non-negative!");
+        assert_eq!(*paths.source(), 1);
+        assert_eq!(paths.distance_to(&1), Some(0));
+        assert_eq!(paths.distance_to(&2), Some(7));
+        assert_eq!(paths.distance_to(&3), Some(9));
+        assert_eq!(paths.distance_to(&4), Some(20));
+        assert_eq!(paths.distance_to(&5), Some(20));
+        assert_eq!(paths.distance_to(&6), Some(11));
+        assert!(paths.unreachable().is_empty());
+    }
+
+    #[test]
+    fn paths_of_wikipedia_example() {
+        let g = helper_make_graph();
+
+        let paths = dijkstra(&g, &1).expect("All weights are non-negative!");
+        let path = paths.path_to(&5).unwrap();
+        assert_eq!(path.vertices, vec![1, 3, 6, 5]);
+        assert_eq!(path.cost, 20);
+
+        assert_eq!(paths.path_to(&1).unwrap().vertices, vec![1]);
+        assert_eq!(paths.predecessor_of(&1), None);
+        assert_eq!(paths.predecessor_of(&4), Some(&3));
+    }
+
+    #[test]
+    fn unreachable_vertices_are_reported() {
+        let mut g = helper_make_graph();
+        g.add_vertex(7).expect("Adding a unique vertex should've worked!");
+        g.add_edge_with(&7, &1, 1).expect("Adding this edge should've worked!");
+
+        let paths = dijkstra(&g, &1).expect("All weights are non-negative!");
+        assert_eq!(paths.unreachable(), &[7]);
+        assert_eq!(paths.distance_to(&7), None);
+        assert_eq!(paths.path_to(&7), None);
+        assert!(!paths.is_reachable(&7));
+        assert!(paths.is_reachable(&5));
+    }
+
+    #[test]
+    fn point_to_point_search() {
+        let mut g = helper_make_graph();
+        g.add_vertex(7).expect("Adding a unique vertex should've worked!");
+
+        let path = dijkstra_to(&g, &2, &6).expect("All weights are non-negative!").unwrap();
+        assert_eq!(path.vertices, vec![2, 3, 6]);
+        assert_eq!(path.cost, 12);
+
+        assert_eq!(dijkstra_to(&g, &1, &7), Ok(None));
+        assert_eq!(dijkstra_to(&g, &1, &42), Err(GraphError::MissingTargetVertex));
+        assert_eq!(dijkstra_to(&g, &42, &1), Err(GraphError::MissingSourceVertex));
+    }
+
+    #[test]
+    fn point_to_point_search_stops_early() {
+        // The negative edge is only looked at after the target has been
+        // settled, so the early exit never sees it
+        let mut g_neg: DiGraph<i64, i32> = DiGraph::new();
+        g_neg.add_vertex(1).expect("Adding a unique vertex should've worked!");
+        g_neg.add_edge_with(&1, &2, 1).expect("Adding this edge should've worked!");
+        g_neg.add_edge_with(&1, &3, 5).expect("Adding this edge should've worked!");
+        g_neg.add_edge_with(&3, &4, -1).expect("Adding this edge should've worked!");
+
+        assert_eq!(dijkstra_to(&g_neg, &1, &2).unwrap().unwrap().cost, 1);
+        assert_eq!(dijkstra(&g_neg, &1).err(), Some(GraphError::NegativeWeight));
+    }
+
+    #[test]
+    fn float_weights() {
+        let mut g: DiGraph<&'static str, f64> = DiGraph::new();
+
+        g.add_vertex("a").expect("Adding a unique vertex should've worked!");
+        g.add_edge_with(&"a", &"b", 0.5).expect("Adding this edge should've worked!");
+        g.add_edge_with(&"b", &"c", 0.25).expect("Adding this edge should've worked!");
+        g.add_edge_with(&"a", &"c", 1.0).expect("Adding this edge should've worked!");
+
+        let paths = dijkstra(&g, &"a").expect("All weights are non-negative!");
+        assert_eq!(paths.distance_to(&"c"), Some(0.75));
+        assert_eq!(paths.path_to(&"c").unwrap().vertices, vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn missing_source_is_err() {
+        let g = helper_make_graph();
+        assert_eq!(dijkstra(&g, &42).err(), Some(GraphError::MissingSourceVertex));
+    }
+}
@@ -0,0 +1,207 @@
+#![allow(clippy::bool_assert_comparison)]
+
+#[cfg(test)]
+mod dominators {
+    use dsa_in_rust::graphs::digraph::DiGraph;
+    use dsa_in_rust::graphs::dominators::*;
+    use dsa_in_rust::graphs::error::GraphError;
+    use dsa_in_rust::misc::lfsr::LFSR;
+    use std::collections::HashSet;
+
+    fn helper_make_graph() -> DiGraph<u32> {
+        // 0 is the entry, 1 is a loop header with an if-else (2, 3)
+        // joining at 4, 5 jumps back to the header or falls out to 6
+        let mut g: DiGraph<u32> = DiGraph::new();
+
+        g.add_vertex(0).expect("Adding a unique vertex should've worked!");
+        let edges = [(0, 1), (1, 2), (1, 3), (2, 4), (3, 4), (4, 5), (5, 1), (5, 6)];
+        for (u, v) in edges.iter() {
+            g.add_edge(u, v).expect("Adding this edge should've worked!");
+        }
+
+        g
+    }
+
+    fn helper_sorted_frontier(d: &Dominators<u32>, v: u32) -> Vec<u32> {
+        let mut frontier = d.dominance_frontier(&v).unwrap().to_vec();
+        frontier.sort();
+        frontier
+    }
+
+    /// Everything reachable from `root` without going through `avoid`
+    fn helper_reachable_without(g: &DiGraph<u32>, root: u32, avoid: Option<u32>) -> HashSet<u32> {
+        let mut seen: HashSet<u32> = HashSet::new();
+        if avoid == Some(root) {
+            return seen;
+        }
+        let mut stack = vec![root];
+        seen.insert(root);
+        while let Some(u) = stack.pop() {
+            for v in g.neighbors_of(&u).unwrap() {
+                if Some(*v) != avoid && seen.insert(*v) {
+                    stack.push(*v);
+                }
+            }
+        }
+        seen
+    }
+
+    #[test]
+    fn immediate_dominators_of_loop() {
+        let g = helper_make_graph();
+
+        let d = dominators(&g, &0).unwrap();
+        assert_eq!(*d.root(), 0);
+        assert_eq!(d.immediate_dominator(&0), None);
+        assert_eq!(d.immediate_dominator(&1), Some(&0));
+        assert_eq!(d.immediate_dominator(&2), Some(&1));
+        assert_eq!(d.immediate_dominator(&3), Some(&1));
+        assert_eq!(d.immediate_dominator(&4), Some(&1));
+        assert_eq!(d.immediate_dominator(&5), Some(&4));
+        assert_eq!(d.immediate_dominator(&6), Some(&5));
+        assert_eq!(d.dominators_of(&6), Some(vec![6, 5, 4, 1, 0]));
+        assert_eq!(d.dominates(&4, &6), true);
+        assert_eq!(d.dominates(&2, &4), false);
+        assert_eq!(d.dominates(&3, &3), true);
+        assert_eq!(d.vertices()[0], 0);
+    }
+
+    #[test]
+    fn dominance_frontiers_of_loop() {
+        let g = helper_make_graph();
+
+        let d = dominators(&g, &0).unwrap();
+        assert_eq!(helper_sorted_frontier(&d, 0), vec![]);
+        assert_eq!(helper_sorted_frontier(&d, 1), vec![1]);
+        assert_eq!(helper_sorted_frontier(&d, 2), vec![4]);
+        assert_eq!(helper_sorted_frontier(&d, 3), vec![4]);
+        assert_eq!(helper_sorted_frontier(&d, 4), vec![1]);
+        assert_eq!(helper_sorted_frontier(&d, 5), vec![1]);
+        assert_eq!(helper_sorted_frontier(&d, 6), vec![]);
+    }
+
+    #[test]
+    fn post_dominators_of_loop() {
+        let g = helper_make_graph();
+
+        let d = post_dominators(&g, &6).unwrap();
+        assert_eq!(d.immediate_dominator(&0), Some(&1));
+        assert_eq!(d.immediate_dominator(&1), Some(&4));
+        assert_eq!(d.immediate_dominator(&2), Some(&4));
+        assert_eq!(d.immediate_dominator(&3), Some(&4));
+        assert_eq!(d.immediate_dominator(&4), Some(&5));
+        assert_eq!(d.immediate_dominator(&5), Some(&6));
+
+        // Control dependencies: the branches depend on 1, the loop body on 5
+        assert_eq!(helper_sorted_frontier(&d, 2), vec![1]);
+        assert_eq!(helper_sorted_frontier(&d, 3), vec![1]);
+        assert_eq!(helper_sorted_frontier(&d, 1), vec![5]);
+        assert_eq!(helper_sorted_frontier(&d, 4), vec![5]);
+        assert_eq!(helper_sorted_frontier(&d, 5), vec![5]);
+        assert_eq!(helper_sorted_frontier(&d, 0), vec![]);
+    }
+
+    #[test]
+    fn irreducible_graph() {
+        // See: Cooper, Harvey and Kennedy, "A Simple, Fast Dominance Algorithm", Figure 4
+        let mut g: DiGraph<u32> = DiGraph::new();
+        g.add_vertex(6).expect("Adding a unique vertex should've worked!");
+        let edges = [(6, 5), (6, 4), (5, 1), (4, 2), (4, 3), (1, 2), (2, 1), (2, 3), (3, 2)];
+        for (u, v) in edges.iter() {
+            g.add_edge(u, v).expect("Adding this edge should've worked!");
+        }
+
+        let d = dominators(&g, &6).unwrap();
+        for v in 1..=5 {
+            assert_eq!(d.immediate_dominator(&v), Some(&6));
+        }
+    }
+
+    #[test]
+    fn unreachable_vertices_are_left_out() {
+        let mut g = helper_make_graph();
+        g.add_vertex(7).expect("Adding a unique vertex should've worked!");
+        g.add_edge(&7, &4).expect("Adding this edge should've worked!");
+
+        // 7 can't be reached, so it doesn't get in the way of 1 dominating 4
+        let d = dominators(&g, &0).unwrap();
+        assert_eq!(d.is_reachable(&7), false);
+        assert_eq!(d.immediate_dominator(&4), Some(&1));
+        assert_eq!(d.dominators_of(&7), None);
+        assert_eq!(d.dominance_frontier(&7), None);
+        assert_eq!(d.dominates(&7, &4), false);
+        assert_eq!(d.vertices().len(), 7);
+
+        assert_eq!(dominators(&g, &42).unwrap_err(), GraphError::MissingVertex);
+        assert_eq!(post_dominators(&g, &42).unwrap_err(), GraphError::MissingVertex);
+    }
+
+    #[test]
+    fn dominator_tree_is_built() {
+        let g = helper_make_graph();
+
+        let tree = dominators(&g, &0).unwrap().dominator_tree();
+        assert_eq!(tree.num_vertices(), 7);
+        assert_eq!(tree.num_edges(), 6);
+        let mut children: Vec<u32> = tree.neighbors_of(&1).unwrap().cloned().collect();
+        children.sort();
+        assert_eq!(children, vec![2, 3, 4]);
+        assert_eq!(tree.are_neighbors(&5, &6), true);
+    }
+
+    #[test]
+    fn long_chain_does_not_overflow_stack() {
+        let mut g: DiGraph<u32> = DiGraph::new();
+        let n = 50_000;
+
+        g.add_vertex(0).expect("Adding a unique vertex should've worked!");
+        for v in 1..n {
+            g.add_edge(&(v - 1), &v).expect("Adding this edge should've worked!");
+        }
+        let d = dominators(&g, &0).unwrap();
+        assert_eq!(d.immediate_dominator(&(n - 1)), Some(&(n - 2)));
+        let d = post_dominators(&g, &(n - 1)).unwrap();
+        assert_eq!(d.immediate_dominator(&0), Some(&1));
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        let mut rng = LFSR::new();
+        let n = 20;
+
+        for _ in 0..20 {
+            let mut g: DiGraph<u32> = DiGraph::new();
+            for v in 0..n {
+                g.add_vertex(v).expect("Adding a unique vertex should've worked!");
+            }
+            for _ in 0..35 {
+                let u = rng.rand() % n;
+                let v = rng.rand() % n;
+                g.add_edge(&u, &v).expect("Adding this edge should've worked!");
+            }
+
+            let d = dominators(&g, &0).unwrap();
+            let reachable = helper_reachable_without(&g, 0, None);
+            for a in 0..n {
+                // a dominates exactly what it cuts off from the root
+                let cut_off = helper_reachable_without(&g, 0, Some(a));
+                for b in 0..n {
+                    let expected = reachable.contains(&a) && reachable.contains(&b) && (a == b || !cut_off.contains(&b));
+                    assert_eq!(d.dominates(&a, &b), expected);
+                }
+            }
+
+            for a in reachable.iter() {
+                let mut expected: Vec<u32> = reachable.iter()
+                    .filter(|b| {
+                        let strictly = a != *b && d.dominates(a, b);
+                        !strictly && g.predecessors_of(b).unwrap().any(|p| d.dominates(a, p))
+                    })
+                    .cloned()
+                    .collect();
+                expected.sort();
+                assert_eq!(helper_sorted_frontier(&d, *a), expected);
+            }
+        }
+    }
+}
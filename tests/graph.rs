@@ -0,0 +1,157 @@
+#![allow(clippy::bool_assert_comparison)]
+
+#[cfg(test)]
+mod graph {
+    use dsa_in_rust::graphs::dijkstra::dijkstra;
+    use dsa_in_rust::graphs::error::GraphError;
+    use dsa_in_rust::graphs::graph::*;
+    use dsa_in_rust::graphs::traversal::{depth_first_visit, DfsEvent};
+
+    fn helper_make_graph() -> Graph<i64> {
+        let mut g: Graph<i64> = Graph::new();
+
+        // A square 1-2-3-4 with a diagonal 1-3, plus a separate 5-6 and a lonely 7
+        g.add_vertex(1).expect("Inserting a unique vertex should've worked!");
+        g.add_edge(&1, &2).expect("Source vertex SHOULD exist here!");
+        g.add_edge(&2, &3).expect("Source vertex SHOULD exist here!");
+        g.add_edge(&3, &4).expect("Source vertex SHOULD exist here!");
+        g.add_edge(&4, &1).expect("Source vertex SHOULD exist here!");
+        g.add_edge(&1, &3).expect("Source vertex SHOULD exist here!");
+        g.add_vertex(5).expect("Inserting a unique vertex should've worked!");
+        g.add_edge(&5, &6).expect("Source vertex SHOULD exist here!");
+        g.add_vertex(7).expect("Inserting a unique vertex should've worked!");
+
+        g
+    }
+
+    #[test]
+    fn new_graph_has_no_edges_or_vertices() {
+        let g: Graph<i64> = Graph::new();
+        assert_eq!(g.num_edges(), 0);
+        assert_eq!(g.num_vertices(), 0);
+    }
+
+    #[test]
+    fn edges_go_both_ways() {
+        let g = helper_make_graph();
+
+        assert_eq!(g.num_vertices(), 7);
+        assert_eq!(g.num_edges(), 6);
+        assert_eq!(g.are_neighbors(&1, &2), true);
+        assert_eq!(g.are_neighbors(&2, &1), true);
+        assert_eq!(g.are_neighbors(&3, &1), true);
+        assert_eq!(g.are_neighbors(&2, &4), false);
+        assert_eq!(g.are_neighbors(&6, &5), true);
+        assert_eq!(g.neighbors_of(&3).unwrap().eq([2, 4, 1].iter()), true);
+    }
+
+    #[test]
+    fn degrees_are_correct() {
+        let mut g = helper_make_graph();
+
+        assert_eq!(g.degree(&1), Some(3));
+        assert_eq!(g.degree(&2), Some(2));
+        assert_eq!(g.degree(&7), Some(0));
+        assert_eq!(g.degree(&42), None);
+
+        // A self-loop touches the vertex twice
+        g.add_edge(&7, &7).expect("Source vertex SHOULD exist here!");
+        assert_eq!(g.degree(&7), Some(2));
+        assert_eq!(g.num_edges(), 7);
+    }
+
+    #[test]
+    fn removing_edges_removes_both_directions() {
+        let mut g = helper_make_graph();
+
+        // Either order should work
+        g.remove_edge(&3, &1);
+        assert_eq!(g.are_neighbors(&1, &3), false);
+        assert_eq!(g.are_neighbors(&3, &1), false);
+        assert_eq!(g.num_edges(), 5);
+        assert_eq!(g.as_digraph().num_edges(), 10);
+
+        // Removing it again is idempotent
+        g.remove_edge(&1, &3);
+        assert_eq!(g.num_edges(), 5);
+
+        g.add_edge(&7, &7).expect("Source vertex SHOULD exist here!");
+        g.remove_edge(&7, &7);
+        assert_eq!(g.num_edges(), 5);
+        assert_eq!(g.degree(&7), Some(0));
+    }
+
+    #[test]
+    fn removing_vertices_removes_their_edges() {
+        let mut g = helper_make_graph();
+        g.add_edge(&1, &1).expect("Source vertex SHOULD exist here!");
+
+        g.remove_vertex(&1);
+        assert_eq!(g.num_vertices(), 6);
+        assert_eq!(g.num_edges(), 3);
+        assert_eq!(g.degree(&2), Some(1));
+        assert_eq!(g.degree(&3), Some(2));
+        assert_eq!(g.are_neighbors(&2, &1), false);
+
+        g.remove_vertex(&42);
+        assert_eq!(g.num_vertices(), 6);
+    }
+
+    #[test]
+    fn adding_edge_with_bad_source_is_err() {
+        let mut g: Graph<i64> = Graph::new();
+        assert_eq!(g.add_edge(&1, &2), Err(GraphError::MissingSourceVertex));
+        assert_eq!(g.num_edges(), 0);
+    }
+
+    #[test]
+    fn connected_components_are_found() {
+        let g = helper_make_graph();
+
+        let components: Vec<Vec<i64>> = g.connected_components().into_iter().map(|mut c| {
+            c.sort();
+            c
+        }).collect();
+        assert_eq!(components, vec![vec![1, 2, 3, 4], vec![5, 6], vec![7]]);
+    }
+
+    #[test]
+    fn traversals_follow_edges_both_ways() {
+        let g = helper_make_graph();
+
+        let visited: Vec<i64> = g.bfs(&4).unwrap().cloned().collect();
+        assert_eq!(visited, vec![4, 3, 1, 2]);
+
+        let visited: Vec<i64> = g.dfs(&6).unwrap().cloned().collect();
+        assert_eq!(visited, vec![6, 5]);
+
+        assert!(g.bfs(&42).is_err());
+        assert!(g.dfs(&42).is_err());
+
+        let mut tree_edges = 0;
+        depth_first_visit(g.as_digraph(), &1, |e| {
+            if let DfsEvent::TreeEdge(_, _) = e {
+                tree_edges += 1;
+            }
+        }).unwrap();
+        assert_eq!(tree_edges, 3);
+    }
+
+    #[test]
+    fn weighted_edges_share_their_payload() {
+        let mut g: Graph<char, u32> = Graph::new();
+
+        g.add_vertex('a').expect("Inserting a unique vertex should've worked!");
+        g.add_edge_with(&'a', &'b', 4).expect("Source vertex SHOULD exist here!");
+        g.add_edge_with(&'b', &'c', 1).expect("Source vertex SHOULD exist here!");
+        g.add_edge_with(&'c', &'a', 2).expect("Source vertex SHOULD exist here!");
+
+        assert_eq!(g.edge_weight(&'a', &'b'), Some(&4));
+        assert_eq!(g.edge_weight(&'b', &'a'), Some(&4));
+
+        // Directed algorithms work on the undirected graph too
+        let paths = dijkstra(g.as_digraph(), &'b').expect("All weights are non-negative!");
+        assert_eq!(paths.distance_to(&'a'), Some(3));
+        assert_eq!(paths.path_to(&'a').unwrap().vertices, vec!['b', 'c', 'a']);
+    }
+}
@@ -0,0 +1,159 @@
+#[cfg(test)]
+mod indexed_graph {
+    use dsa_in_rust::graphs::digraph::DiGraph;
+    use dsa_in_rust::graphs::dijkstra::dijkstra;
+    use dsa_in_rust::graphs::error::GraphError;
+    use dsa_in_rust::graphs::indexed_graph::*;
+    use dsa_in_rust::graphs::max_flow::dinic;
+    use dsa_in_rust::graphs::topological_sorting::insertion_order_topological_sort;
+    use dsa_in_rust::graphs::traversal::{Bfs, Dfs};
+    use std::rc::Rc;
+
+    fn helper_make_graph() -> (IndexedGraph<String, u32>, Vec<NodeId>) {
+        let mut g: IndexedGraph<String, u32> = IndexedGraph::new();
+        let ids: Vec<NodeId> = ["paris", "berlin", "rome", "madrid"].iter()
+            .map(|city| g.add_vertex(city.to_string()).expect("Adding a unique vertex should've worked!"))
+            .collect();
+        let edges = [(0, 1, 9), (0, 3, 10), (1, 2, 12), (3, 2, 14), (0, 2, 25)];
+        for (u, v, w) in edges.iter() {
+            g.add_edge_with(ids[*u], ids[*v], *w).expect("Adding this edge should've worked!");
+        }
+        (g, ids)
+    }
+
+    #[test]
+    fn vertices_can_be_looked_up_both_ways() {
+        let (g, ids) = helper_make_graph();
+
+        assert_eq!(g.num_vertices(), 4);
+        assert_eq!(g.num_edges(), 5);
+        for (i, id) in ids.iter().enumerate() {
+            assert_eq!(id.index(), i);
+            let city = g.vertex(*id).unwrap();
+            assert_eq!(g.node_id(city), Some(*id));
+        }
+        assert_eq!(g.node_id(&"rome".to_string()), Some(ids[2]));
+        assert_eq!(g.node_id(&"lisbon".to_string()), None);
+        assert!(g.contains(&"madrid".to_string()));
+        assert_eq!(g.node_ids().collect::<Vec<_>>(), ids);
+        assert_eq!(g.vertices().cloned().collect::<Vec<_>>(), vec!["paris", "berlin", "rome", "madrid"]);
+    }
+
+    #[test]
+    fn handles_from_elsewhere_are_rejected() {
+        let (mut g, ids) = helper_make_graph();
+        let (mut bigger, _) = helper_make_graph();
+        let stranger = bigger.add_vertex("lisbon".to_string()).unwrap();
+
+        assert_eq!(g.vertex(stranger), None);
+        assert_eq!(g.add_edge_with(stranger, ids[0], 1), Err(GraphError::MissingSourceVertex));
+        assert_eq!(g.add_edge_with(ids[0], stranger, 1), Err(GraphError::MissingTargetVertex));
+        assert_eq!(g.successors(stranger).map(|s| s.count()), None);
+        assert_eq!(g.num_edges(), 5);
+    }
+
+    #[test]
+    fn duplicates_are_rejected_but_interning_is_idempotent() {
+        let (mut g, ids) = helper_make_graph();
+
+        assert_eq!(g.add_vertex("rome".to_string()), Err(GraphError::DuplicateVertex));
+        assert_eq!(g.intern("rome".to_string()), ids[2]);
+        let lisbon = g.intern("lisbon".to_string());
+        assert_eq!(lisbon.index(), 4);
+        assert_eq!(g.intern("lisbon".to_string()), lisbon);
+        assert_eq!(g.num_vertices(), 5);
+    }
+
+    #[test]
+    fn vertices_are_never_cloned() {
+        let mut g: IndexedGraph<Rc<str>> = IndexedGraph::new();
+        let a: Rc<str> = Rc::from("a");
+        let b: Rc<str> = Rc::from("b");
+        let a_id = g.add_vertex(Rc::clone(&a)).expect("Adding a unique vertex should've worked!");
+        let b_id = g.intern(Rc::clone(&b));
+        g.add_edge(a_id, b_id).expect("Adding this edge should've worked!");
+        g.add_edge(b_id, a_id).expect("Adding this edge should've worked!");
+        assert_eq!(g.intern(Rc::clone(&a)), a_id);
+
+        // Our copy plus the graph's one
+        assert_eq!(Rc::strong_count(&a), 2);
+        assert_eq!(Rc::strong_count(&b), 2);
+        assert_eq!(g.node_id(&b), Some(b_id));
+    }
+
+    #[test]
+    fn edges_have_their_own_handles() {
+        let mut g: IndexedGraph<&str> = IndexedGraph::new();
+        let a = g.add_vertex("a").expect("Adding a unique vertex should've worked!");
+        let b = g.add_vertex("b").expect("Adding a unique vertex should've worked!");
+        let first = g.add_edge(a, b).expect("Adding this edge should've worked!");
+        let second = g.add_edge(a, b).expect("Adding this edge should've worked!");
+        let back = g.add_edge(b, a).expect("Adding this edge should've worked!");
+
+        assert_ne!(first, second);
+        assert_eq!(g.edge_ids().collect::<Vec<_>>(), vec![first, second, back]);
+        assert_eq!(g.edge_endpoints(second), Some((a, b)));
+        assert_eq!(g.edge_endpoints(back), Some((b, a)));
+        assert_eq!(g.find_edge(a, b), Some(first));
+        assert_eq!(g.outgoing_edges(a), Some(&[first, second][..]));
+        assert_eq!(g.incoming_edges(a), Some(&[back][..]));
+        assert_eq!(g.successors(a).unwrap().collect::<Vec<_>>(), vec![b, b]);
+        assert_eq!(g.predecessors(b).unwrap().collect::<Vec<_>>(), vec![a, a]);
+        assert_eq!(g.out_degree(&"a"), Some(2));
+        assert_eq!(g.in_degree(&"a"), Some(1));
+        assert_eq!(g.in_degree(&"z"), None);
+        assert_eq!(g.predecessors_of(&"b").unwrap().collect::<Vec<_>>(), vec![&"a", &"a"]);
+        assert!(g.predecessors_of(&"z").is_none());
+    }
+
+    #[test]
+    fn payloads_can_be_changed() {
+        let (mut g, ids) = helper_make_graph();
+        let edge = g.find_edge(ids[0], ids[2]).unwrap();
+
+        assert_eq!(g.edge_payload(edge), Some(&25));
+        *g.edge_payload_mut(edge).unwrap() = 5;
+        assert_eq!(g.edge_weight(&"paris".to_string(), &"rome".to_string()), Some(&5));
+        assert_eq!(g.edge_weight(&"rome".to_string(), &"paris".to_string()), None);
+    }
+
+    #[test]
+    fn algorithms_work_on_the_vertices() {
+        let (g, _) = helper_make_graph();
+        let paris = "paris".to_string();
+
+        let bfs: Vec<&String> = Bfs::new(&g, &paris).unwrap().collect();
+        assert_eq!(bfs, vec!["paris", "berlin", "madrid", "rome"]);
+        let dfs: Vec<&String> = Dfs::new(&g, &paris).unwrap().collect();
+        assert_eq!(dfs, vec!["paris", "berlin", "rome", "madrid"]);
+
+        let paths = dijkstra(&g, &paris).expect("All weights are non-negative!");
+        let to_rome = paths.path_to(&"rome".to_string()).unwrap();
+        assert_eq!(to_rome.vertices, vec!["paris", "berlin", "rome"]);
+        assert_eq!(to_rome.cost, 21);
+
+        let order = insertion_order_topological_sort(&g).expect("The graph is acyclic!");
+        assert_eq!(order, vec!["paris", "berlin", "madrid", "rome"]);
+        let flow = dinic(&g, &paris, &"rome".to_string()).expect("Both ends are in the graph!");
+        assert_eq!(flow.value, 9 + 10 + 25);
+    }
+
+    #[test]
+    fn converting_a_digraph_keeps_everything() {
+        let mut g: DiGraph<char, i8> = DiGraph::new();
+        for v in ['x', 'y', 'z'] {
+            g.add_vertex(v).expect("Adding a unique vertex should've worked!");
+        }
+        g.add_edge_with(&'z', &'x', 1).expect("Adding this edge should've worked!");
+        g.add_edge_with(&'x', &'z', 2).expect("Adding this edge should've worked!");
+        g.add_edge_with(&'x', &'y', 3).expect("Adding this edge should've worked!");
+
+        let indexed = IndexedGraph::from(&g);
+        assert_eq!(indexed.vertices().copied().collect::<Vec<_>>(), vec!['x', 'y', 'z']);
+        assert_eq!(indexed.num_edges(), 3);
+        assert_eq!(indexed.edges_of(&'x').unwrap().collect::<Vec<_>>(), vec![(&'z', &2), (&'y', &3)]);
+        assert_eq!(indexed.neighbors_of(&'z').unwrap().collect::<Vec<_>>(), vec![&'x']);
+        assert!(indexed.are_neighbors(&'z', &'x'));
+        assert!(!indexed.are_neighbors(&'y', &'x'));
+    }
+}
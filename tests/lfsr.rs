@@ -12,8 +12,8 @@ mod lfsr {
         let mut l = LFSR::new();
         let expected = [1, 1];
         println!("{:b}", l.get_state());
-        for i in 0..expected.len() {
-            assert_eq!(l.next_bit(), expected[i]);
+        for bit in expected.iter() {
+            assert_eq!(l.next_bit(), *bit);
             println!("{:032b}", l.get_state());
         }
     }
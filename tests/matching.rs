@@ -0,0 +1,143 @@
+#[cfg(test)]
+mod matching {
+    use dsa_in_rust::graphs::digraph::DiGraph;
+    use dsa_in_rust::graphs::error::GraphError;
+    use dsa_in_rust::graphs::graph::Graph;
+    use dsa_in_rust::graphs::matching::*;
+    use dsa_in_rust::graphs::max_flow::dinic;
+    use dsa_in_rust::misc::lfsr::LFSR;
+    use std::collections::HashSet;
+
+    /// Workers are positive, jobs are negative
+    fn helper_make_graph() -> Graph<i64> {
+        let mut g: Graph<i64> = Graph::new();
+
+        for worker in 1..=5 {
+            g.add_vertex(worker).expect("Adding a unique vertex should've worked!");
+        }
+        // Workers 1 and 2 can only do job 1, so one of them has to go without
+        let edges = [(1, -1), (2, -1), (3, -1), (3, -2), (3, -3), (4, -3), (4, -4), (5, -4)];
+        for (u, v) in edges.iter() {
+            g.add_edge(u, v).expect("Adding this edge should've worked!");
+        }
+
+        g
+    }
+
+    /// Checks that every pair is an edge and that no vertex is used twice
+    fn helper_check_matching(g: &DiGraph<i64>, matching: &Matching<i64>) {
+        let mut used: HashSet<i64> = HashSet::new();
+        for (u, v) in matching.pairs().iter() {
+            assert!(g.are_neighbors(u, v) || g.are_neighbors(v, u));
+            assert!(used.insert(*u));
+            assert!(used.insert(*v));
+            assert_eq!(matching.mate_of(u), Some(v));
+            assert_eq!(matching.mate_of(v), Some(u));
+        }
+    }
+
+    #[test]
+    fn bipartition_splits_graph() {
+        let g = helper_make_graph();
+
+        let (left, right) = bipartition(g.as_digraph()).unwrap();
+        assert_eq!(left, vec![1, 2, 3, 4, 5]);
+        assert_eq!(right, vec![-1, -2, -3, -4]);
+    }
+
+    #[test]
+    fn odd_cycles_are_not_bipartite() {
+        let mut g: DiGraph<i64> = DiGraph::new();
+        g.add_vertex(1).expect("Adding a unique vertex should've worked!");
+        g.add_edge(&1, &2).expect("Adding this edge should've worked!");
+        g.add_edge(&2, &3).expect("Adding this edge should've worked!");
+        assert!(bipartition(&g).is_ok());
+
+        // Direction doesn't matter
+        g.add_edge(&1, &3).expect("Adding this edge should've worked!");
+        assert_eq!(bipartition(&g).unwrap_err(), GraphError::NotBipartite);
+        assert_eq!(hopcroft_karp(&g).unwrap_err(), GraphError::NotBipartite);
+
+        let mut g: DiGraph<i64> = DiGraph::new();
+        g.add_vertex(1).expect("Adding a unique vertex should've worked!");
+        g.add_edge(&1, &1).expect("Adding this edge should've worked!");
+        assert_eq!(bipartition(&g).unwrap_err(), GraphError::NotBipartite);
+    }
+
+    #[test]
+    fn maximum_matching_is_found() {
+        let g = helper_make_graph();
+
+        let matching = hopcroft_karp(g.as_digraph()).unwrap();
+        assert_eq!(matching.len(), 4);
+        helper_check_matching(g.as_digraph(), &matching);
+        assert_eq!(matching.mate_of(&3), Some(&-2));
+        assert_eq!(matching.mate_of(&5), Some(&-4));
+        assert_eq!(matching.mate_of(&4), Some(&-3));
+        assert!(matching.mate_of(&1).is_none() || matching.mate_of(&2).is_none());
+    }
+
+    #[test]
+    fn sides_can_be_chosen() {
+        let mut g: DiGraph<i64> = DiGraph::new();
+        // Job -1 was added before any worker, so it would end up on the left
+        g.add_vertex(-1).expect("Adding a unique vertex should've worked!");
+        g.add_edge(&-1, &1).expect("Adding this edge should've worked!");
+        g.add_edge(&-1, &2).expect("Adding this edge should've worked!");
+        g.add_edge(&-2, &2).unwrap_err();
+        g.add_edge(&2, &-2).expect("Adding this edge should've worked!");
+
+        let matching = hopcroft_karp_with(&g, |v| *v > 0).unwrap();
+        assert_eq!(matching.pairs(), &[(1, -1), (2, -2)]);
+
+        let err = hopcroft_karp_with(&g, |v| *v != 2).unwrap_err();
+        assert_eq!(err, GraphError::NotBipartite);
+    }
+
+    #[test]
+    fn empty_graph_has_empty_matching() {
+        let g: DiGraph<i64> = DiGraph::new();
+
+        let matching = hopcroft_karp(&g).unwrap();
+        assert!(matching.is_empty());
+        assert_eq!(matching.mate_of(&1), None);
+    }
+
+    #[test]
+    fn matches_max_flow() {
+        let mut rng = LFSR::new();
+        let n = 300;
+
+        for _ in 0..5 {
+            let mut g: DiGraph<i64> = DiGraph::new();
+            for v in 1..=n {
+                g.add_vertex(v).expect("Adding a unique vertex should've worked!");
+                g.add_vertex(-v).expect("Adding a unique vertex should've worked!");
+            }
+            for _ in 0..600 {
+                let u = (rng.rand() % n as u32) as i64 + 1;
+                let v = (rng.rand() % n as u32) as i64 + 1;
+                g.add_edge(&u, &-v).expect("Adding this edge should've worked!");
+            }
+
+            // Every worker can take one unit of flow, and so can every job
+            let mut network: DiGraph<i64, u32> = DiGraph::new();
+            for v in g.vertices_in_insertion_order() {
+                network.add_vertex(*v).expect("Adding a unique vertex should've worked!");
+            }
+            network.add_vertex(0).expect("Adding a unique vertex should've worked!");
+            for v in 1..=n {
+                network.add_edge_with(&0, &v, 1).expect("Adding this edge should've worked!");
+                network.add_edge_with(&-v, &i64::MAX, 1).expect("Adding this edge should've worked!");
+                for w in g.neighbors_of(&v).unwrap() {
+                    network.add_edge_with(&v, w, 1).expect("Adding this edge should've worked!");
+                }
+            }
+
+            let matching = hopcroft_karp_with(&g, |v| *v > 0).unwrap();
+            helper_check_matching(&g, &matching);
+            let flow = dinic(&network, &0, &i64::MAX).unwrap();
+            assert_eq!(matching.len() as u32, flow.value);
+        }
+    }
+}
@@ -0,0 +1,219 @@
+#![allow(clippy::bool_assert_comparison)]
+
+#[cfg(test)]
+mod matrix_graph {
+    use dsa_in_rust::graphs::a_star::a_star;
+    use dsa_in_rust::graphs::digraph::DiGraph;
+    use dsa_in_rust::graphs::directed_graph::DirectedGraph;
+    use dsa_in_rust::graphs::dominators::post_dominators;
+    use dsa_in_rust::graphs::error::GraphError;
+    use dsa_in_rust::graphs::matching::{bipartition, hopcroft_karp};
+    use dsa_in_rust::graphs::matrix_graph::MatrixGraph;
+    use dsa_in_rust::graphs::strongly_connected::condensation;
+    use dsa_in_rust::graphs::topological_sorting::{insertion_order_topological_sort, topological_layers};
+    use dsa_in_rust::graphs::transitive::transitive_reduction;
+    use dsa_in_rust::graphs::traversal::*;
+    use dsa_in_rust::misc::lfsr::LFSR;
+    use std::rc::Rc;
+
+    fn helper_make_graph() -> MatrixGraph<char> {
+        let mut g: MatrixGraph<char> = MatrixGraph::new();
+        for v in ['a', 'b', 'c', 'd'] {
+            g.add_vertex(v).expect("Adding a unique vertex should've worked!");
+        }
+        let edges = [('a', 'c'), ('a', 'b'), ('b', 'd'), ('c', 'd'), ('d', 'a')];
+        for (u, v) in edges.iter() {
+            g.add_edge(u, v).expect("Adding this edge should've worked!");
+        }
+        g
+    }
+
+    #[test]
+    fn edges_can_be_added_and_removed() {
+        let mut g = helper_make_graph();
+
+        assert_eq!(g.num_vertices(), 4);
+        assert_eq!(g.num_edges(), 5);
+        assert_eq!(g.are_neighbors(&'a', &'b'), true);
+        assert_eq!(g.are_neighbors(&'b', &'a'), false);
+        assert_eq!(g.are_neighbors(&'a', &'z'), false);
+        assert_eq!(g.add_vertex('a'), Err(GraphError::DuplicateVertex));
+        assert_eq!(g.add_edge(&'z', &'a'), Err(GraphError::MissingSourceVertex));
+
+        // Already there, so nothing changes
+        g.add_edge(&'a', &'b').expect("Adding this edge should've worked!");
+        assert_eq!(g.num_edges(), 5);
+
+        g.remove_edge(&'a', &'b');
+        g.remove_edge(&'a', &'b');
+        assert_eq!(g.are_neighbors(&'a', &'b'), false);
+        assert_eq!(g.num_edges(), 4);
+    }
+
+    #[test]
+    fn missing_targets_are_added() {
+        let mut g = helper_make_graph();
+        g.add_edge(&'d', &'e').expect("Adding this edge should've worked!");
+
+        assert_eq!(g.num_vertices(), 5);
+        assert_eq!(g.index_of(&'e'), Some(4));
+        assert_eq!(g.vertex(4), Some(&'e'));
+        assert_eq!(g.are_neighbors(&'d', &'e'), true);
+        assert_eq!(g.out_degree(&'e'), Some(0));
+    }
+
+    #[test]
+    fn vertices_are_stored_once() {
+        let mut g: MatrixGraph<Rc<str>> = MatrixGraph::new();
+        let a: Rc<str> = Rc::from("a");
+        let b: Rc<str> = Rc::from("b");
+        g.add_vertex(Rc::clone(&a)).expect("Adding a unique vertex should've worked!");
+        g.add_edge(&a, &b).expect("Adding this edge should've worked!");
+
+        // Our copy plus the graph's one
+        assert_eq!(Rc::strong_count(&a), 2);
+        assert_eq!(Rc::strong_count(&b), 2);
+        assert_eq!(g.index_of(&b), Some(1));
+    }
+
+    #[test]
+    fn neighbors_come_out_in_vertex_order() {
+        let g = helper_make_graph();
+
+        assert_eq!(g.neighbors_of(&'a').unwrap().collect::<Vec<_>>(), vec![&'b', &'c']);
+        assert_eq!(g.edges_of(&'d').unwrap().collect::<Vec<_>>(), vec![(&'a', &())]);
+        assert!(g.neighbors_of(&'z').is_none());
+        assert_eq!(g.out_degree(&'a'), Some(2));
+        assert_eq!(g.in_degree(&'d'), Some(2));
+        assert_eq!(g.in_degree(&'z'), None);
+        assert_eq!(g.predecessors_of(&'d').unwrap().collect::<Vec<_>>(), vec![&'b', &'c']);
+        assert_eq!(g.predecessors_of(&'a').unwrap().collect::<Vec<_>>(), vec![&'d']);
+        assert!(g.predecessors_of(&'z').is_none());
+    }
+
+    #[test]
+    fn rows_grow_past_a_word() {
+        let mut g: MatrixGraph<u32> = MatrixGraph::new();
+        g.add_vertex(0).expect("Adding a unique vertex should've worked!");
+        for v in 1..200 {
+            g.add_edge(&0, &v).expect("Adding this edge should've worked!");
+            g.add_edge(&v, &0).expect("Adding this edge should've worked!");
+        }
+
+        assert_eq!(g.num_vertices(), 200);
+        assert_eq!(g.num_edges(), 398);
+        assert_eq!(g.out_degree(&0), Some(199));
+        assert_eq!(g.in_degree(&0), Some(199));
+        assert_eq!(g.are_neighbors(&199, &0), true);
+        assert_eq!(g.are_neighbors(&1, &199), false);
+    }
+
+    #[test]
+    fn converts_to_and_from_digraphs() {
+        let mut rng = LFSR::new();
+        let n = 70;
+
+        for _ in 0..5 {
+            let mut g: DiGraph<u32> = DiGraph::new();
+            for v in 0..n {
+                g.add_vertex(v).expect("Adding a unique vertex should've worked!");
+            }
+            for _ in 0..(n * n / 3) {
+                g.add_edge(&(rng.rand() % n), &(rng.rand() % n)).expect("Adding this edge should've worked!");
+            }
+
+            let matrix = MatrixGraph::from(&g);
+            assert_eq!(matrix.num_vertices(), g.num_vertices());
+            for u in 0..n {
+                for v in 0..n {
+                    assert_eq!(matrix.are_neighbors(&u, &v), g.are_neighbors(&u, &v));
+                }
+            }
+
+            // Parallel edges are gone, but everything else survives the round trip
+            let back: DiGraph<u32> = (&matrix).into();
+            assert_eq!(back.num_edges(), matrix.num_edges());
+            assert_eq!(back.vertices_in_insertion_order(), g.vertices_in_insertion_order());
+            for u in 0..n {
+                for v in 0..n {
+                    assert_eq!(back.are_neighbors(&u, &v), g.are_neighbors(&u, &v));
+                }
+            }
+        }
+    }
+
+    fn helper_hops<G: DirectedGraph<Vertex = char>>(g: &G, from: char, to: char) -> Option<u32> {
+        a_star(g, &from, |v| *v == to, |_, _, _| 1, |_| 0)
+            .expect("The start is in the graph!")
+            .map(|path| path.cost)
+    }
+
+    #[test]
+    fn algorithms_work_on_both_representations() {
+        let matrix = helper_make_graph();
+        let g = matrix.to_digraph();
+
+        assert_eq!(Bfs::full(&matrix).collect::<Vec<_>>(), Bfs::full(&g).collect::<Vec<_>>());
+        assert_eq!(Dfs::new(&matrix, &'b').unwrap().collect::<Vec<_>>(), Dfs::new(&g, &'b').unwrap().collect::<Vec<_>>());
+
+        let mut back_edges = Vec::new();
+        depth_first_visit_all(&matrix, |event| {
+            if let DfsEvent::BackEdge(u, v) = event {
+                back_edges.push((*u, *v));
+            }
+        });
+        assert_eq!(back_edges, vec![('d', 'a')]);
+
+        for from in ['a', 'b', 'c', 'd'] {
+            for to in ['a', 'b', 'c', 'd'] {
+                assert_eq!(helper_hops(&matrix, from, to), helper_hops(&g, from, to));
+            }
+        }
+        assert_eq!(helper_hops(&matrix, 'b', 'c'), Some(3));
+    }
+
+    #[test]
+    fn graph_algorithms_work_on_both_representations() {
+        let mut rng = LFSR::new();
+        let n = 24;
+
+        for _ in 0..5 {
+            // Dense, and only ever from an even vertex to an odd one, so
+            // it's bipartite and acyclic
+            let mut g: DiGraph<u32> = DiGraph::new();
+            for v in 0..n {
+                g.add_vertex(v).expect("Adding a unique vertex should've worked!");
+            }
+            for u in (0..n).step_by(2) {
+                for v in (u + 1..n).step_by(2) {
+                    if !rng.rand().is_multiple_of(3) {
+                        g.add_edge(&u, &v).expect("Adding this edge should've worked!");
+                    }
+                }
+            }
+            let matrix = MatrixGraph::from(&g);
+
+            // Within a layer, the vertices come out in the order of `vertices()`
+            let sorted = |layers: Vec<Vec<u32>>| -> Vec<Vec<u32>> {
+                layers.into_iter().map(|mut layer| { layer.sort(); layer }).collect()
+            };
+            assert_eq!(sorted(topological_layers(&matrix).unwrap()), sorted(topological_layers(&g).unwrap()));
+            assert_eq!(insertion_order_topological_sort(&matrix).unwrap(), insertion_order_topological_sort(&g).unwrap());
+            assert_eq!(condensation(&matrix).components.len(), n as usize);
+            assert_eq!(bipartition(&matrix).unwrap(), bipartition(&g).unwrap());
+            assert_eq!(hopcroft_karp(&matrix).unwrap().len(), hopcroft_karp(&g).unwrap().len());
+
+            let reduction = transitive_reduction(&matrix).unwrap();
+            let expected_reduction = transitive_reduction(&g).unwrap();
+            let post = post_dominators(&matrix, &(n - 1)).unwrap();
+            let expected_post = post_dominators(&g, &(n - 1)).unwrap();
+            assert_eq!(reduction.num_edges(), expected_reduction.num_edges());
+            for u in 0..n {
+                assert_eq!(post.immediate_dominator(&u), expected_post.immediate_dominator(&u));
+                for v in 0..n {
+                    assert_eq!(reduction.are_neighbors(&u, &v), expected_reduction.are_neighbors(&u, &v));
+                }
+            }
+        }
+    }
+}
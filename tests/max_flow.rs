@@ -0,0 +1,156 @@
+#[cfg(test)]
+mod max_flow {
+    use dsa_in_rust::graphs::digraph::DiGraph;
+    use dsa_in_rust::graphs::error::GraphError;
+    use dsa_in_rust::graphs::max_flow::*;
+    use dsa_in_rust::misc::lfsr::LFSR;
+    use std::collections::{HashMap, HashSet};
+
+    fn helper_make_graph() -> DiGraph<char, u32> {
+        // See: CLRS, Figure 26.1
+        let mut g: DiGraph<char, u32> = DiGraph::new();
+
+        g.add_vertex('s').expect("Adding a unique vertex should've worked!");
+        let edges = [('s', 'a', 16), ('s', 'c', 13), ('a', 'b', 12), ('c', 'a', 4),
+                     ('b', 'c', 9), ('c', 'd', 14), ('d', 'b', 7), ('b', 't', 20),
+                     ('d', 't', 4)];
+        for (u, v, w) in edges.iter() {
+            g.add_edge_with(u, v, *w).expect("Adding this edge should've worked!");
+        }
+
+        g
+    }
+
+    /// Checks that the flow is actually a valid flow of the right value:
+    /// no edge goes over its capacity, and everything that goes into a
+    /// vertex comes back out, other than at the source and the sink.
+    fn helper_check_flow(g: &DiGraph<i64, u64>, flow: &MaxFlow<i64, u64>, source: i64, sink: i64) {
+        let mut net: HashMap<i64, i64> = HashMap::new();
+        let mut capacities = Vec::new();
+        for u in g.vertices_in_insertion_order() {
+            for (v, capacity) in g.edges_of(u).unwrap() {
+                capacities.push((*u, *v, *capacity));
+            }
+        }
+        assert_eq!(capacities.len(), flow.flows.len());
+        for ((u, v, capacity), (fu, fv, f)) in capacities.iter().zip(flow.flows.iter()) {
+            assert_eq!((u, v), (fu, fv));
+            assert!(f <= capacity);
+            *net.entry(*u).or_insert(0) -= *f as i64;
+            *net.entry(*v).or_insert(0) += *f as i64;
+        }
+        for (v, total) in net {
+            if v == source {
+                assert_eq!(total, -(flow.value as i64));
+            } else if v == sink {
+                assert_eq!(total, flow.value as i64);
+            } else {
+                assert_eq!(total, 0);
+            }
+        }
+
+        // The cut is full, and exactly as big as the flow
+        assert!(flow.source_side.contains(&source));
+        assert!(!flow.source_side.contains(&sink));
+        let cut_capacity: u64 = capacities.iter()
+            .filter(|(u, v, _c)| flow.source_side.contains(u) && !flow.source_side.contains(v))
+            .map(|(_u, _v, c)| c)
+            .sum();
+        assert_eq!(cut_capacity, flow.value);
+    }
+
+    #[test]
+    fn maximum_flow_of_clrs_example() {
+        let g = helper_make_graph();
+
+        for flow in [edmonds_karp(&g, &'s', &'t').unwrap(), dinic(&g, &'s', &'t').unwrap()].iter() {
+            assert_eq!(flow.value, 23);
+            assert_eq!(flow.flows.len(), 9);
+            assert_eq!(flow.flow_between(&'b', &'t') + flow.flow_between(&'d', &'t'), 23);
+            assert_eq!(flow.flow_between(&'d', &'t'), 4);
+            assert_eq!(flow.flow_between(&'t', &'d'), 0);
+        }
+    }
+
+    #[test]
+    fn minimum_cut_of_clrs_example() {
+        let g = helper_make_graph();
+
+        for flow in [edmonds_karp(&g, &'s', &'t').unwrap(), dinic(&g, &'s', &'t').unwrap()].iter() {
+            let expected: HashSet<char> = ['s', 'a', 'c', 'd'].iter().cloned().collect();
+            assert_eq!(flow.source_side, expected);
+
+            let mut cut = flow.cut_edges();
+            cut.sort();
+            assert_eq!(cut, vec![('a', 'b', 12), ('d', 'b', 7), ('d', 't', 4)]);
+        }
+    }
+
+    #[test]
+    fn unreachable_sink_has_no_flow() {
+        let mut g = helper_make_graph();
+        g.add_vertex('x').expect("Adding a unique vertex should've worked!");
+        g.add_edge_with(&'x', &'s', 100).expect("Adding this edge should've worked!");
+
+        for flow in [edmonds_karp(&g, &'s', &'x').unwrap(), dinic(&g, &'s', &'x').unwrap()].iter() {
+            assert_eq!(flow.value, 0);
+            assert!(flow.flows.iter().all(|(_u, _v, f)| *f == 0));
+            assert_eq!(flow.source_side.len(), 6);
+            assert!(flow.cut_edges().is_empty());
+        }
+    }
+
+    #[test]
+    fn parallel_edges_and_self_loops() {
+        let mut g: DiGraph<i64, f64> = DiGraph::new();
+        g.add_vertex(1).expect("Adding a unique vertex should've worked!");
+        g.add_edge_with(&1, &2, 1.5).expect("Adding this edge should've worked!");
+        g.add_edge_with(&1, &2, 2.0).expect("Adding this edge should've worked!");
+        g.add_edge_with(&2, &2, 10.0).expect("Adding this edge should've worked!");
+        g.add_edge_with(&2, &3, 10.0).expect("Adding this edge should've worked!");
+
+        for flow in [edmonds_karp(&g, &1, &3).unwrap(), dinic(&g, &1, &3).unwrap()].iter() {
+            assert_eq!(flow.value, 3.5);
+            assert_eq!(flow.flows, vec![(1, 2, 1.5), (1, 2, 2.0), (2, 2, 0.0), (2, 3, 3.5)]);
+            assert_eq!(flow.flow_between(&1, &2), 3.5);
+        }
+    }
+
+    #[test]
+    fn bad_inputs_are_err() {
+        let g = helper_make_graph();
+        assert_eq!(edmonds_karp(&g, &'z', &'t').unwrap_err(), GraphError::MissingSourceVertex);
+        assert_eq!(dinic(&g, &'s', &'z').unwrap_err(), GraphError::MissingTargetVertex);
+        assert_eq!(dinic(&g, &'s', &'s').unwrap_err(), GraphError::SourceIsSink);
+
+        let mut g: DiGraph<i64, i32> = DiGraph::new();
+        g.add_vertex(1).expect("Adding a unique vertex should've worked!");
+        g.add_edge_with(&1, &2, -1).expect("Adding this edge should've worked!");
+        assert_eq!(edmonds_karp(&g, &1, &2).unwrap_err(), GraphError::NegativeWeight);
+        assert_eq!(dinic(&g, &1, &2).unwrap_err(), GraphError::NegativeWeight);
+    }
+
+    #[test]
+    fn edmonds_karp_and_dinic_agree() {
+        let mut rng = LFSR::new();
+        let mut g: DiGraph<i64, u64> = DiGraph::new();
+        let n = 200;
+
+        for v in 0..n {
+            g.add_vertex(v).expect("Adding a unique vertex should've worked!");
+        }
+        for _ in 0..2000 {
+            let u = (rng.rand() % n as u32) as i64;
+            let v = (rng.rand() % n as u32) as i64;
+            let w = (rng.rand() % 100) as u64;
+            g.add_edge_with(&u, &v, w).expect("Adding this edge should've worked!");
+        }
+
+        let ek = edmonds_karp(&g, &0, &(n - 1)).unwrap();
+        let d = dinic(&g, &0, &(n - 1)).unwrap();
+        assert!(ek.value > 0);
+        assert_eq!(ek.value, d.value);
+        helper_check_flow(&g, &ek, 0, n - 1);
+        helper_check_flow(&g, &d, 0, n - 1);
+    }
+}
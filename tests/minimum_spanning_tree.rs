@@ -0,0 +1,126 @@
+#[cfg(test)]
+mod minimum_spanning_tree {
+    use dsa_in_rust::graphs::graph::Graph;
+    use dsa_in_rust::graphs::minimum_spanning_tree::*;
+    use dsa_in_rust::misc::lfsr::LFSR;
+
+    fn helper_make_graph() -> Graph<i64, u32> {
+        // Same as the Dijkstra example on Wikipedia
+        let mut g: Graph<i64, u32> = Graph::new();
+
+        for v in 1..=6 {
+            g.add_vertex(v).expect("Adding a unique vertex should've worked!");
+        }
+        let edges = [(1, 2, 7), (1, 3, 9), (1, 6, 14), (2, 3, 10), (2, 4, 15),
+                     (3, 4, 11), (3, 6, 2), (4, 5, 6), (5, 6, 9)];
+        for (u, v, w) in edges.iter() {
+            g.add_edge_with(u, v, *w).expect("Adding this edge should've worked!");
+        }
+
+        g
+    }
+
+    /// Puts each edge's endpoints in order, then sorts the edges, so
+    /// forests can be compared no matter which way the edges were found.
+    fn helper_sorted_edges<W: Copy>(forest: &SpanningForest<i64, W>) -> Vec<(i64, i64)> {
+        let mut edges: Vec<(i64, i64)> = forest.edges.iter()
+            .map(|(u, v, _w)| (*u.min(v), *u.max(v)))
+            .collect();
+        edges.sort();
+        edges
+    }
+
+    #[test]
+    fn kruskal_finds_minimum_spanning_tree() {
+        let g = helper_make_graph();
+
+        let forest = kruskal(&g);
+        assert_eq!(forest.total_weight, 33);
+        assert_eq!(forest.num_trees, 1);
+        assert!(forest.is_tree());
+        assert_eq!(helper_sorted_edges(&forest), vec![(1, 2), (1, 3), (3, 6), (4, 5), (5, 6)]);
+        // Lightest first
+        assert_eq!(forest.edges[0], (3, 6, 2));
+    }
+
+    #[test]
+    fn prim_finds_minimum_spanning_tree() {
+        let g = helper_make_graph();
+
+        let forest = prim(&g);
+        assert_eq!(forest.total_weight, 33);
+        assert!(forest.is_tree());
+        assert_eq!(helper_sorted_edges(&forest), vec![(1, 2), (1, 3), (3, 6), (4, 5), (5, 6)]);
+        // Grown outwards from the first vertex
+        assert_eq!(forest.edges[0], (1, 2, 7));
+    }
+
+    #[test]
+    fn disconnected_graph_gives_forest() {
+        let mut g = helper_make_graph();
+        g.add_vertex(7).expect("Adding a unique vertex should've worked!");
+        g.add_edge_with(&7, &8, 3).expect("Adding this edge should've worked!");
+        g.add_edge_with(&8, &9, 1).expect("Adding this edge should've worked!");
+        g.add_edge_with(&9, &7, 5).expect("Adding this edge should've worked!");
+        g.add_vertex(10).expect("Adding a unique vertex should've worked!");
+
+        for forest in [kruskal(&g), prim(&g)].iter() {
+            assert_eq!(forest.num_trees, 3);
+            assert!(!forest.is_tree());
+            assert_eq!(forest.total_weight, 33 + 4);
+            assert_eq!(forest.edges.len(), 10 - 3);
+        }
+    }
+
+    #[test]
+    fn self_loops_and_parallel_edges() {
+        let mut g: Graph<i64, i32> = Graph::new();
+        g.add_vertex(1).expect("Adding a unique vertex should've worked!");
+        g.add_edge_with(&1, &1, -10).expect("Adding this edge should've worked!");
+        g.add_edge_with(&1, &2, 8).expect("Adding this edge should've worked!");
+        g.add_edge_with(&2, &1, -3).expect("Adding this edge should've worked!");
+        g.add_edge_with(&1, &2, 5).expect("Adding this edge should've worked!");
+
+        for forest in [kruskal(&g), prim(&g)].iter() {
+            assert_eq!(forest.edges.len(), 1);
+            assert_eq!(forest.total_weight, -3);
+        }
+    }
+
+    #[test]
+    fn empty_graph_is_a_tree() {
+        let g: Graph<i64, f64> = Graph::new();
+
+        for forest in [kruskal(&g), prim(&g)].iter() {
+            assert!(forest.edges.is_empty());
+            assert_eq!(forest.total_weight, 0.0);
+            assert_eq!(forest.num_trees, 0);
+            assert!(forest.is_tree());
+        }
+    }
+
+    #[test]
+    fn kruskal_and_prim_agree() {
+        let mut rng = LFSR::new();
+        let mut g: Graph<i64, u64> = Graph::new();
+        let n = 500;
+
+        for v in 0..n {
+            g.add_vertex(v).expect("Adding a unique vertex should've worked!");
+        }
+        for _ in 0..5000 {
+            let u = (rng.rand() % n as u32) as i64;
+            let v = (rng.rand() % n as u32) as i64;
+            let w = (rng.rand() % 1000) as u64;
+            g.add_edge_with(&u, &v, w).expect("Adding this edge should've worked!");
+        }
+
+        let k = kruskal(&g);
+        let p = prim(&g);
+        assert_eq!(k.total_weight, p.total_weight);
+        assert_eq!(k.num_trees, p.num_trees);
+        assert_eq!(k.edges.len(), p.edges.len());
+        assert_eq!(k.edges.len() + k.num_trees, n as usize);
+        assert_eq!(g.connected_components().len(), k.num_trees);
+    }
+}
@@ -0,0 +1,129 @@
+#![allow(clippy::bool_assert_comparison)]
+
+#[cfg(test)]
+mod reachability {
+    use dsa_in_rust::graphs::digraph::DiGraph;
+    use dsa_in_rust::graphs::reachability::*;
+    use dsa_in_rust::graphs::traversal::Bfs;
+    use dsa_in_rust::misc::lfsr::LFSR;
+    use std::collections::HashSet;
+
+    fn helper_make_graph() -> DiGraph<u32> {
+        // A cycle 1 -> 2 -> 3 -> 1 that leads to 4 -> 5, and a lonely 6 -> 4
+        let mut g: DiGraph<u32> = DiGraph::new();
+
+        g.add_vertex(1).expect("Adding a unique vertex should've worked!");
+        let edges = [(1, 2), (2, 3), (3, 1), (3, 4), (4, 5)];
+        for (u, v) in edges.iter() {
+            g.add_edge(u, v).expect("Adding this edge should've worked!");
+        }
+        g.add_vertex(6).expect("Adding a unique vertex should've worked!");
+        g.add_edge(&6, &4).expect("Adding this edge should've worked!");
+
+        g
+    }
+
+    #[test]
+    fn reaches_follows_paths() {
+        let g = helper_make_graph();
+
+        let index = ReachabilityIndex::new(&g);
+        assert_eq!(index.num_vertices(), 6);
+        assert_eq!(index.reaches(&1, &5), true);
+        assert_eq!(index.reaches(&3, &2), true);
+        assert_eq!(index.reaches(&6, &5), true);
+        assert_eq!(index.reaches(&5, &4), false);
+        assert_eq!(index.reaches(&6, &1), false);
+        assert_eq!(index.reaches(&4, &6), false);
+    }
+
+    #[test]
+    fn vertices_reach_themselves() {
+        let g = helper_make_graph();
+
+        let index = ReachabilityIndex::new(&g);
+        for v in 1..=6 {
+            assert_eq!(index.reaches(&v, &v), true);
+        }
+    }
+
+    #[test]
+    fn unknown_vertices_reach_nothing() {
+        let g = helper_make_graph();
+
+        let index = ReachabilityIndex::new(&g);
+        assert_eq!(index.contains(&42), false);
+        assert_eq!(index.reaches(&42, &42), false);
+        assert_eq!(index.reaches(&1, &42), false);
+        assert_eq!(index.reaches(&42, &1), false);
+    }
+
+    #[test]
+    fn rebuild_picks_up_changes() {
+        let mut g = helper_make_graph();
+        let mut index = ReachabilityIndex::new(&g);
+
+        g.add_edge(&5, &6).expect("Adding this edge should've worked!");
+        g.add_edge(&6, &7).expect("Adding this edge should've worked!");
+        assert_eq!(index.reaches(&4, &6), false);
+
+        index.rebuild(&g);
+        assert_eq!(index.reaches(&4, &6), true);
+        assert_eq!(index.reaches(&1, &7), true);
+        assert_eq!(index.reaches(&7, &1), false);
+        assert_eq!(index.num_vertices(), 7);
+    }
+
+    #[test]
+    fn matches_bfs() {
+        let mut rng = LFSR::new();
+        let n = 200;
+
+        for _ in 0..5 {
+            let mut g: DiGraph<u32> = DiGraph::new();
+            for v in 0..n {
+                g.add_vertex(v).expect("Adding a unique vertex should've worked!");
+            }
+            for _ in 0..250 {
+                let u = rng.rand() % n;
+                let v = rng.rand() % n;
+                g.add_edge(&u, &v).expect("Adding this edge should've worked!");
+            }
+
+            let index = ReachabilityIndex::new(&g);
+            for u in 0..n {
+                let reachable: HashSet<u32> = Bfs::new(&g, &u).unwrap().cloned().collect();
+                for v in 0..n {
+                    assert_eq!(index.reaches(&u, &v), reachable.contains(&v));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn large_graphs_are_fine() {
+        // A binary tree of 50,000 vertices, with extra edges across the
+        // branches that the spanning forest can't cover
+        let n: u32 = 50_000;
+        let mut g: DiGraph<u32> = DiGraph::new();
+        for v in 0..n {
+            g.add_vertex(v).expect("Adding a unique vertex should've worked!");
+        }
+        for v in 0..n {
+            for w in [2 * v + 1, 2 * v + 2, v + 7] {
+                if w < n && (w != v + 7 || v % 5 == 0) {
+                    g.add_edge(&v, &w).expect("Adding this edge should've worked!");
+                }
+            }
+        }
+
+        let index = ReachabilityIndex::new(&g);
+        assert_eq!(index.num_vertices(), n as usize);
+        for u in [0, 1, 2, 123, 4_567, 49_999].iter() {
+            let reachable: HashSet<u32> = Bfs::new(&g, u).unwrap().cloned().collect();
+            for v in (0..n).step_by(211) {
+                assert_eq!(index.reaches(u, &v), reachable.contains(&v));
+            }
+        }
+    }
+}
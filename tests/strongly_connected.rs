@@ -0,0 +1,165 @@
+#[cfg(test)]
+mod strongly_connected {
+    use dsa_in_rust::graphs::digraph::DiGraph;
+    use dsa_in_rust::graphs::strongly_connected::*;
+    use dsa_in_rust::graphs::topological_sorting::topological_sort;
+
+    fn helper_sorted_components(g: &DiGraph<i64>) -> Vec<Vec<i64>> {
+        let mut components: Vec<Vec<i64>> = strongly_connected_components(g)
+            .into_iter()
+            .map(|mut c| {
+                c.sort();
+                c
+            })
+            .collect();
+        components.sort();
+        components
+    }
+
+    #[test]
+    fn empty_graph_has_no_components() {
+        let g: DiGraph<i64> = DiGraph::new();
+        assert!(strongly_connected_components(&g).is_empty());
+    }
+
+    #[test]
+    fn acyclic_graph_has_singleton_components() {
+        let mut g: DiGraph<i64> = DiGraph::new();
+
+        g.add_vertex(1).expect("Adding a unique vertex should've worked!");
+        g.add_edge(&1, &2).expect("Adding this edge should've worked!");
+        g.add_edge(&1, &3).expect("Adding this edge should've worked!");
+        g.add_edge(&2, &3).expect("Adding this edge should've worked!");
+
+        assert_eq!(helper_sorted_components(&g), vec![vec![1], vec![2], vec![3]]);
+    }
+
+    #[test]
+    fn finds_components_of_textbook_example() {
+        // See: https://en.wikipedia.org/wiki/Strongly_connected_component
+        let mut g: DiGraph<i64> = DiGraph::new();
+
+        g.add_vertex(1).expect("Adding a unique vertex should've worked!");
+        g.add_edge(&1, &2).expect("Adding this edge should've worked!");
+        g.add_edge(&2, &3).expect("Adding this edge should've worked!");
+        g.add_edge(&2, &5).expect("Adding this edge should've worked!");
+        g.add_edge(&2, &6).expect("Adding this edge should've worked!");
+        g.add_edge(&3, &4).expect("Adding this edge should've worked!");
+        g.add_edge(&3, &7).expect("Adding this edge should've worked!");
+        g.add_edge(&4, &3).expect("Adding this edge should've worked!");
+        g.add_edge(&4, &8).expect("Adding this edge should've worked!");
+        g.add_edge(&5, &1).expect("Adding this edge should've worked!");
+        g.add_edge(&5, &6).expect("Adding this edge should've worked!");
+        g.add_edge(&6, &7).expect("Adding this edge should've worked!");
+        g.add_edge(&7, &6).expect("Adding this edge should've worked!");
+        g.add_edge(&8, &4).expect("Adding this edge should've worked!");
+        g.add_edge(&8, &7).expect("Adding this edge should've worked!");
+
+        assert_eq!(
+            helper_sorted_components(&g),
+            vec![vec![1, 2, 5], vec![3, 4, 8], vec![6, 7]]
+        );
+    }
+
+    #[test]
+    fn components_are_in_reverse_topological_order() {
+        let mut g: DiGraph<i64> = DiGraph::new();
+
+        // {1, 2} -> {3, 4} -> {5}
+        g.add_vertex(1).expect("Adding a unique vertex should've worked!");
+        g.add_edge(&1, &2).expect("Adding this edge should've worked!");
+        g.add_edge(&2, &1).expect("Adding this edge should've worked!");
+        g.add_edge(&2, &3).expect("Adding this edge should've worked!");
+        g.add_edge(&3, &4).expect("Adding this edge should've worked!");
+        g.add_edge(&4, &3).expect("Adding this edge should've worked!");
+        g.add_edge(&4, &5).expect("Adding this edge should've worked!");
+
+        let components = strongly_connected_components(&g);
+        assert_eq!(components.len(), 3);
+        assert_eq!(components[0], vec![5]);
+        assert!(components[1].contains(&3) && components[1].contains(&4));
+        assert!(components[2].contains(&1) && components[2].contains(&2));
+    }
+
+    #[test]
+    fn long_cycle_does_not_overflow_the_stack() {
+        let mut g: DiGraph<i64> = DiGraph::new();
+
+        g.add_vertex(0).expect("Adding a unique vertex should've worked!");
+        for i in 0..100_000 {
+            g.add_edge(&i, &(i + 1)).expect("Adding this edge should've worked!");
+        }
+        g.add_edge(&100_000, &0).expect("Adding this edge should've worked!");
+
+        let components = strongly_connected_components(&g);
+        assert_eq!(components.len(), 1);
+        assert_eq!(components[0].len(), 100_001);
+    }
+
+    #[test]
+    fn condensation_collapses_cycles() {
+        let mut g: DiGraph<i64> = DiGraph::new();
+
+        // {1, 2} -> {3, 4} -> {5}, with a few redundant edges between them
+        g.add_vertex(1).expect("Adding a unique vertex should've worked!");
+        g.add_edge(&1, &2).expect("Adding this edge should've worked!");
+        g.add_edge(&2, &1).expect("Adding this edge should've worked!");
+        g.add_edge(&1, &3).expect("Adding this edge should've worked!");
+        g.add_edge(&2, &3).expect("Adding this edge should've worked!");
+        g.add_edge(&2, &4).expect("Adding this edge should've worked!");
+        g.add_edge(&3, &4).expect("Adding this edge should've worked!");
+        g.add_edge(&4, &3).expect("Adding this edge should've worked!");
+        g.add_edge(&4, &5).expect("Adding this edge should've worked!");
+        g.add_edge(&5, &5).expect("Adding this edge should've worked!");
+
+        let c = condensation(&g);
+        assert_eq!(c.components.len(), 3);
+        assert_eq!(c.graph.num_vertices(), 3);
+        assert_eq!(c.graph.num_edges(), 2);
+
+        let a = c.component_of[&1];
+        let b = c.component_of[&3];
+        let e = c.component_of[&5];
+        assert_eq!(c.component_of[&2], a);
+        assert_eq!(c.component_of[&4], b);
+        assert!(c.graph.are_neighbors(&a, &b));
+        assert!(c.graph.are_neighbors(&b, &e));
+        assert!(!c.graph.are_neighbors(&e, &e));
+
+        // Ids are already in topological order
+        assert_eq!(topological_sort(&c.graph).expect("Condensations are acyclic!"), vec![0, 1, 2]);
+        assert_eq!((a, b, e), (0, 1, 2));
+    }
+
+    #[test]
+    fn condensation_of_textbook_example_is_acyclic() {
+        let mut g: DiGraph<i64> = DiGraph::new();
+
+        g.add_vertex(1).expect("Adding a unique vertex should've worked!");
+        g.add_edge(&1, &2).expect("Adding this edge should've worked!");
+        g.add_edge(&2, &3).expect("Adding this edge should've worked!");
+        g.add_edge(&2, &5).expect("Adding this edge should've worked!");
+        g.add_edge(&2, &6).expect("Adding this edge should've worked!");
+        g.add_edge(&3, &4).expect("Adding this edge should've worked!");
+        g.add_edge(&3, &7).expect("Adding this edge should've worked!");
+        g.add_edge(&4, &3).expect("Adding this edge should've worked!");
+        g.add_edge(&4, &8).expect("Adding this edge should've worked!");
+        g.add_edge(&5, &1).expect("Adding this edge should've worked!");
+        g.add_edge(&5, &6).expect("Adding this edge should've worked!");
+        g.add_edge(&6, &7).expect("Adding this edge should've worked!");
+        g.add_edge(&7, &6).expect("Adding this edge should've worked!");
+        g.add_edge(&8, &4).expect("Adding this edge should've worked!");
+        g.add_edge(&8, &7).expect("Adding this edge should've worked!");
+
+        let c = condensation(&g);
+        assert_eq!(c.graph.num_vertices(), 3);
+        // {1, 2, 5} -> {3, 4, 8}, {1, 2, 5} -> {6, 7}, {3, 4, 8} -> {6, 7}
+        assert_eq!(c.graph.num_edges(), 3);
+        for (id, component) in c.components.iter().enumerate() {
+            for v in component {
+                assert_eq!(c.component_of[v], id);
+            }
+        }
+        assert!(topological_sort(&c.graph).is_ok());
+    }
+}
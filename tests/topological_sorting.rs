@@ -2,7 +2,14 @@
 #[cfg(test)]
 mod topological_sorting {
     use dsa_in_rust::graphs::digraph::DiGraph;
-    use dsa_in_rust::graphs::topological_sorting::topological_sort;
+    use dsa_in_rust::graphs::error::GraphError;
+    use dsa_in_rust::graphs::topological_sorting::*;
+
+    fn helper_is_cycle(g: &DiGraph<i64>, cycle: &[i64]) -> bool {
+        !cycle.is_empty() && (0..cycle.len()).all(|i| {
+            g.are_neighbors(&cycle[i], &cycle[(i + 1) % cycle.len()])
+        })
+    }
 
     #[test]
     fn produces_correct_result_on_acyclic_graph() {
@@ -21,7 +28,7 @@ mod topological_sorting {
         g.add_edge(&5, &7).expect("Adding this edge should've worked!");
         g.add_edge(&6, &7).expect("Adding this edge should've worked!");
 
-        let sorted = topological_sort(&mut g).expect("This graph is acylic!");
+        let sorted = topological_sort(&g).expect("This graph is acylic!");
         assert!(sorted.eq(&[1,2,3,4,5,6,7]));
     }
 
@@ -36,8 +43,17 @@ mod topological_sorting {
         g.add_edge(&3, &4).expect("Adding this edge should've worked!");
         g.add_edge(&4, &1).expect("Adding this edge should've worked!");
 
-        let sorted = topological_sort(&mut g);
-        assert!(sorted.is_none());
+        let err = topological_sort(&g).expect_err("This graph is cyclic!");
+        assert_eq!(err.cycle.len(), 4);
+        assert!(helper_is_cycle(&g, &err.cycle));
+
+        assert_eq!(err.components.len(), 1);
+        let mut component = err.components[0].clone();
+        component.sort();
+        assert_eq!(component, vec![1, 2, 3, 4]);
+
+        // The cycle can still be reported as a plain GraphError
+        assert_eq!(GraphError::from(err), GraphError::CycleDetected);
     }
 
     #[test]
@@ -68,9 +84,344 @@ mod topological_sorting {
 
         g.add_edge(&8, &9).expect("Adding this edge should've worked!");
 
-        let sorted = topological_sort(&mut g).expect("This graph is acyclic!");
+        let sorted = topological_sort(&g).expect("This graph is acyclic!");
         // The result is non-deterministic because of the backing hashmap and there
         // being 3 different valid start locations
         println!("Topological ordering is: {:?}", sorted);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn sorting_leaves_graph_intact() {
+        let mut g: DiGraph<i64> = DiGraph::new();
+
+        g.add_vertex(1).expect("Adding a unique vertex should've worked!");
+        g.add_edge(&1, &2).expect("Adding this edge should've worked!");
+        g.add_edge(&1, &3).expect("Adding this edge should've worked!");
+        g.add_edge(&2, &3).expect("Adding this edge should've worked!");
+
+        let sorted = topological_sort(&g).expect("This graph is acyclic!");
+        assert!(sorted.eq(&[1, 2, 3]));
+
+        assert_eq!(g.num_vertices(), 3);
+        assert_eq!(g.num_edges(), 3);
+        assert!(g.are_neighbors(&1, &2));
+        assert!(g.are_neighbors(&1, &3));
+        assert!(g.are_neighbors(&2, &3));
+
+        // Sorting the same graph twice should give the same answer
+        assert_eq!(topological_sort(&g).expect("This graph is acyclic!"), sorted);
+    }
+
+    #[test]
+    fn cycle_witness_ignores_vertices_downstream_of_cycle() {
+        let mut g: DiGraph<i64> = DiGraph::new();
+
+        // 0 -> 1 -> 2 -> 3 -> 1, 3 -> 4 -> 5, plus a self-loop on 5
+        g.add_vertex(0).expect("Adding a unique vertex should've worked!");
+        g.add_edge(&0, &1).expect("Adding this edge should've worked!");
+        g.add_edge(&1, &2).expect("Adding this edge should've worked!");
+        g.add_edge(&2, &3).expect("Adding this edge should've worked!");
+        g.add_edge(&3, &1).expect("Adding this edge should've worked!");
+        g.add_edge(&3, &4).expect("Adding this edge should've worked!");
+        g.add_edge(&4, &5).expect("Adding this edge should've worked!");
+        g.add_edge(&5, &5).expect("Adding this edge should've worked!");
+
+        let err = topological_sort(&g).expect_err("This graph is cyclic!");
+        assert!(helper_is_cycle(&g, &err.cycle));
+        assert!(err.cycle.len() == 1 || err.cycle.len() == 3);
+
+        let mut components: Vec<Vec<i64>> = err.components.into_iter().map(|mut c| {
+            c.sort();
+            c
+        }).collect();
+        components.sort();
+        assert_eq!(components, vec![vec![1, 2, 3], vec![5]]);
+    }
+
+    #[test]
+    fn cycle_error_display_closes_the_loop() {
+        let err = CycleError { cycle: vec![1, 2, 3], components: vec![vec![1, 2, 3]] };
+        assert_eq!(err.to_string(), "Graph contains a cycle: 1 -> 2 -> 3 -> 1");
+    }
+
+    fn helper_sorted_layers(layers: Vec<Vec<i64>>) -> Vec<Vec<i64>> {
+        layers.into_iter().map(|mut layer| {
+            layer.sort();
+            layer
+        }).collect()
+    }
+
+    #[test]
+    fn layers_of_wikipedia_example() {
+        let mut g: DiGraph<i64> = DiGraph::new();
+
+        g.add_vertex(5).expect("Adding a unique vertex should've worked!");
+        g.add_vertex(7).expect("Adding a unique vertex should've worked!");
+        g.add_vertex(3).expect("Adding a unique vertex should've worked!");
+
+        g.add_edge(&5, &11).expect("Adding this edge should've worked!");
+        g.add_edge(&7, &11).expect("Adding this edge should've worked!");
+        g.add_edge(&7, &8).expect("Adding this edge should've worked!");
+        g.add_edge(&3, &8).expect("Adding this edge should've worked!");
+        g.add_edge(&3, &10).expect("Adding this edge should've worked!");
+        g.add_edge(&11, &2).expect("Adding this edge should've worked!");
+        g.add_edge(&11, &9).expect("Adding this edge should've worked!");
+        g.add_edge(&11, &10).expect("Adding this edge should've worked!");
+        g.add_edge(&8, &9).expect("Adding this edge should've worked!");
+
+        let layers = topological_layers(&g).expect("This graph is acyclic!");
+        assert_eq!(
+            helper_sorted_layers(layers),
+            vec![vec![3, 5, 7], vec![8, 11], vec![2, 9, 10]]
+        );
+    }
+
+    #[test]
+    fn layers_wait_for_every_dependency() {
+        let mut g: DiGraph<i64> = DiGraph::new();
+
+        // 3 depends on both 1 and 2, but 2 is only ready after 1
+        g.add_vertex(1).expect("Adding a unique vertex should've worked!");
+        g.add_edge(&1, &2).expect("Adding this edge should've worked!");
+        g.add_edge(&1, &3).expect("Adding this edge should've worked!");
+        g.add_edge(&2, &3).expect("Adding this edge should've worked!");
+        g.add_vertex(4).expect("Adding a unique vertex should've worked!");
+
+        let layers = topological_layers(&g).expect("This graph is acyclic!");
+        assert_eq!(helper_sorted_layers(layers), vec![vec![1, 4], vec![2], vec![3]]);
+    }
+
+    #[test]
+    fn layers_of_empty_graph_is_empty() {
+        let g: DiGraph<i64> = DiGraph::new();
+        assert!(topological_layers(&g).expect("This graph is acyclic!").is_empty());
+    }
+
+    #[test]
+    fn layers_of_cyclic_graph_is_err() {
+        let mut g: DiGraph<i64> = DiGraph::new();
+
+        g.add_vertex(1).expect("Adding a unique vertex should've worked!");
+        g.add_edge(&1, &2).expect("Adding this edge should've worked!");
+        g.add_edge(&2, &3).expect("Adding this edge should've worked!");
+        g.add_edge(&3, &2).expect("Adding this edge should've worked!");
+
+        let err = topological_layers(&g).expect_err("This graph is cyclic!");
+        assert!(helper_is_cycle(&g, &err.cycle));
+        assert_eq!(err.cycle.len(), 2);
+    }
+
+    fn helper_make_wikipedia_graph() -> DiGraph<i64> {
+        let mut g: DiGraph<i64> = DiGraph::new();
+
+        g.add_vertex(5).expect("Adding a unique vertex should've worked!");
+        g.add_vertex(7).expect("Adding a unique vertex should've worked!");
+        g.add_vertex(3).expect("Adding a unique vertex should've worked!");
+        g.add_vertex(8).expect("Adding a unique vertex should've worked");
+        g.add_vertex(2).expect("Adding a unique vertex should've worked");
+        g.add_vertex(9).expect("Adding a unique vertex should've worked");
+        g.add_vertex(11).expect("Adding a unique vertex should've worked");
+        g.add_vertex(10).expect("Adding a unique vertex should've worked");
+
+        g.add_edge(&5, &11).expect("Adding this edge should've worked!");
+        g.add_edge(&7, &11).expect("Adding this edge should've worked!");
+        g.add_edge(&7, &8).expect("Adding this edge should've worked!");
+        g.add_edge(&3, &8).expect("Adding this edge should've worked!");
+        g.add_edge(&3, &10).expect("Adding this edge should've worked!");
+        g.add_edge(&11, &2).expect("Adding this edge should've worked!");
+        g.add_edge(&11, &9).expect("Adding this edge should've worked!");
+        g.add_edge(&11, &10).expect("Adding this edge should've worked!");
+        g.add_edge(&8, &9).expect("Adding this edge should've worked!");
+
+        g
+    }
+
+    #[test]
+    fn lexicographic_sort_of_wikipedia_example() {
+        let g = helper_make_wikipedia_graph();
+
+        let sorted = lexicographic_topological_sort(&g).expect("This graph is acyclic!");
+        assert_eq!(sorted, vec![3, 5, 7, 8, 11, 2, 9, 10]);
+    }
+
+    #[test]
+    fn insertion_order_sort_of_wikipedia_example() {
+        let g = helper_make_wikipedia_graph();
+
+        let sorted = insertion_order_topological_sort(&g).expect("This graph is acyclic!");
+        assert_eq!(sorted, vec![5, 7, 3, 8, 11, 2, 9, 10]);
+    }
+
+    #[test]
+    fn insertion_order_sort_keeps_order_when_possible() {
+        let mut g: DiGraph<i64> = DiGraph::new();
+
+        for v in [9, 4, 7, 1, 8].iter() {
+            g.add_vertex(*v).expect("Adding a unique vertex should've worked!");
+        }
+        g.add_edge(&9, &1).expect("Adding this edge should've worked!");
+        g.add_edge(&4, &8).expect("Adding this edge should've worked!");
+
+        let sorted = insertion_order_topological_sort(&g).expect("This graph is acyclic!");
+        assert_eq!(sorted, vec![9, 4, 7, 1, 8]);
+    }
+
+    #[test]
+    fn deterministic_sorts_of_cyclic_graph_are_err() {
+        let mut g = helper_make_wikipedia_graph();
+        g.add_edge(&10, &3).expect("Adding this edge should've worked!");
+
+        let err = lexicographic_topological_sort(&g).expect_err("This graph is cyclic!");
+        assert!(helper_is_cycle(&g, &err.cycle));
+
+        let err = insertion_order_topological_sort(&g).expect_err("This graph is cyclic!");
+        assert!(helper_is_cycle(&g, &err.cycle));
+    }
+
+    #[test]
+    fn all_sorts_of_small_graph() {
+        let mut g: DiGraph<i64> = DiGraph::new();
+
+        // 1 -> 2 -> 4 and 1 -> 3 -> 4, so 2 and 3 can go in either order
+        g.add_vertex(1).expect("Adding a unique vertex should've worked!");
+        g.add_edge(&1, &2).expect("Adding this edge should've worked!");
+        g.add_edge(&1, &3).expect("Adding this edge should've worked!");
+        g.add_edge(&2, &4).expect("Adding this edge should've worked!");
+        g.add_edge(&3, &4).expect("Adding this edge should've worked!");
+
+        let sorts: Vec<Vec<i64>> = all_topological_sorts(&g).collect();
+        assert_eq!(sorts, vec![vec![1, 2, 3, 4], vec![1, 3, 2, 4]]);
+        assert_eq!(count_topological_sorts(&g), Some(2));
+    }
+
+    #[test]
+    fn all_sorts_of_wikipedia_example_are_valid_and_unique() {
+        let g = helper_make_wikipedia_graph();
+
+        let sorts: Vec<Vec<i64>> = all_topological_sorts(&g).collect();
+        assert_eq!(count_topological_sorts(&g), Some(sorts.len() as u128));
+        assert_eq!(sorts[0], insertion_order_topological_sort(&g).unwrap());
+
+        for sort in sorts.iter() {
+            assert_eq!(sort.len(), 8);
+            let position = |v: &i64| sort.iter().position(|w| w == v).unwrap();
+            for u in sort.iter() {
+                for v in g.neighbors_of(u).unwrap() {
+                    assert!(position(u) < position(v));
+                }
+            }
+        }
+
+        let mut deduped = sorts.clone();
+        deduped.sort();
+        deduped.dedup();
+        assert_eq!(deduped.len(), sorts.len());
+    }
+
+    #[test]
+    fn all_sorts_of_edgeless_graph_are_permutations() {
+        let mut g: DiGraph<i64> = DiGraph::new();
+        for v in 0..5 {
+            g.add_vertex(v).expect("Adding a unique vertex should've worked!");
+        }
+
+        assert_eq!(all_topological_sorts(&g).count(), 120);
+        assert_eq!(count_topological_sorts(&g), Some(120));
+
+        // Counting doesn't need to enumerate, so this is quick even though
+        // there are 16! orderings.
+        for v in 5..16 {
+            g.add_vertex(v).expect("Adding a unique vertex should've worked!");
+        }
+        assert_eq!(count_topological_sorts(&g), Some(20_922_789_888_000));
+    }
+
+    #[test]
+    fn all_sorts_of_degenerate_graphs() {
+        let mut g: DiGraph<i64> = DiGraph::new();
+
+        // The empty ordering is the one and only ordering of an empty graph
+        assert_eq!(all_topological_sorts(&g).collect::<Vec<_>>(), vec![Vec::<i64>::new()]);
+        assert_eq!(count_topological_sorts(&g), Some(1));
+
+        g.add_vertex(1).expect("Adding a unique vertex should've worked!");
+        g.add_edge(&1, &2).expect("Adding this edge should've worked!");
+        g.add_edge(&2, &1).expect("Adding this edge should've worked!");
+
+        assert_eq!(all_topological_sorts(&g).next(), None);
+        assert_eq!(count_topological_sorts(&g), Some(0));
+    }
+
+    #[test]
+    fn counting_too_many_sorts_is_none() {
+        // Three independent chains of 41 vertices can be interleaved in
+        // 123! / (41!)^3 ways, which is more than a u128 can hold
+        let mut g: DiGraph<i64> = DiGraph::new();
+        for chain in 0..3 {
+            g.add_vertex(chain * 100).expect("Adding a unique vertex should've worked!");
+            for v in 1..41 {
+                let v = chain * 100 + v;
+                g.add_edge(&(v - 1), &v).expect("Adding this edge should've worked!");
+            }
+        }
+
+        assert_eq!(g.num_vertices(), 123);
+        assert_eq!(count_topological_sorts(&g), None);
+    }
+
+    #[test]
+    fn counting_sorts_of_long_chain() {
+        // Deep enough to overflow the stack if every vertex was a recursive call
+        let mut g: DiGraph<i64> = DiGraph::new();
+        g.add_vertex(0).expect("Adding a unique vertex should've worked!");
+        for v in 1..60_000 {
+            g.add_edge(&(v - 1), &v).expect("Adding this edge should've worked!");
+        }
+
+        assert_eq!(count_topological_sorts(&g), Some(1));
+    }
+
+    fn helper_make_tangled_graph() -> DiGraph<i64> {
+        let mut g: DiGraph<i64> = DiGraph::new();
+        for v in 0..10 {
+            g.add_vertex(v).expect("Adding a unique vertex should've worked!");
+        }
+        let edges = [(0, 1), (1, 2), (2, 3), (3, 1), (2, 4), (4, 5), (5, 4),
+                     (6, 6), (7, 8), (8, 9), (9, 7), (3, 7)];
+        for (u, v) in edges.iter() {
+            g.add_edge(u, v).expect("Adding this edge should've worked!");
+        }
+        g
+    }
+
+    #[test]
+    fn cycle_errors_are_the_same_every_run() {
+        // Every graph gets its own randomly seeded HashMaps, so building the
+        // same graph over and over is enough to shake out any hash ordering
+        let expected_lexicographic = lexicographic_topological_sort(&helper_make_tangled_graph())
+            .expect_err("This graph is cyclic!");
+        let expected_insertion = insertion_order_topological_sort(&helper_make_tangled_graph())
+            .expect_err("This graph is cyclic!");
+        assert_eq!(expected_insertion.components, vec![vec![1, 2, 3], vec![4, 5], vec![6], vec![7, 8, 9]]);
+        assert_eq!(expected_insertion.cycle, vec![2, 3, 1]);
+
+        for _ in 0..30 {
+            let g = helper_make_tangled_graph();
+            assert_eq!(lexicographic_topological_sort(&g).expect_err("This graph is cyclic!"), expected_lexicographic);
+            assert_eq!(insertion_order_topological_sort(&g).expect_err("This graph is cyclic!"), expected_insertion);
+        }
+    }
+
+    #[test]
+    fn sorting_works_on_weighted_graphs() {
+        let mut g: DiGraph<&'static str, u32> = DiGraph::new();
+
+        g.add_vertex("shirt").expect("Adding a unique vertex should've worked!");
+        g.add_edge_with(&"shirt", &"tie", 1).expect("Adding this edge should've worked!");
+        g.add_edge_with(&"tie", &"jacket", 5).expect("Adding this edge should've worked!");
+        g.add_edge_with(&"shirt", &"jacket", 3).expect("Adding this edge should've worked!");
+
+        assert_eq!(topological_sort(&g).expect("This graph is acyclic!"), vec!["shirt", "tie", "jacket"]);
+        assert_eq!(count_topological_sorts(&g), Some(1));
+    }
+}
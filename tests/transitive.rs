@@ -0,0 +1,150 @@
+#![allow(clippy::bool_assert_comparison)]
+
+#[cfg(test)]
+mod transitive {
+    use dsa_in_rust::graphs::digraph::DiGraph;
+    use dsa_in_rust::graphs::transitive::*;
+    use dsa_in_rust::graphs::traversal::Bfs;
+    use dsa_in_rust::misc::lfsr::LFSR;
+    use std::collections::HashSet;
+
+    fn helper_make_dag() -> DiGraph<char> {
+        // a -> b -> c -> d, with shortcuts a -> c, a -> d and b -> d,
+        // plus a separate e -> f
+        let mut g: DiGraph<char> = DiGraph::new();
+
+        g.add_vertex('a').expect("Adding a unique vertex should've worked!");
+        let edges = [('a', 'c'), ('a', 'b'), ('b', 'c'), ('c', 'd'), ('a', 'd'), ('b', 'd')];
+        for (u, v) in edges.iter() {
+            g.add_edge(u, v).expect("Adding this edge should've worked!");
+        }
+        g.add_vertex('e').expect("Adding a unique vertex should've worked!");
+        g.add_edge(&'e', &'f').expect("Adding this edge should've worked!");
+
+        g
+    }
+
+    /// Every vertex reachable from `u` by following at least one edge
+    fn helper_reachable<E>(g: &DiGraph<u32, E>, u: u32) -> HashSet<u32> {
+        let mut reachable = HashSet::new();
+        for v in g.neighbors_of(&u).unwrap() {
+            for w in Bfs::new(g, v).unwrap() {
+                reachable.insert(*w);
+            }
+        }
+        reachable
+    }
+
+    #[test]
+    fn closure_of_dag() {
+        let g = helper_make_dag();
+
+        let closure = transitive_closure(&g);
+        assert_eq!(closure.num_vertices(), 6);
+        assert_eq!(closure.num_edges(), 7);
+        assert_eq!(closure.neighbors_of(&'a').unwrap().eq(['c', 'b', 'd'].iter()), true);
+        assert_eq!(closure.neighbors_of(&'c').unwrap().eq(['d'].iter()), true);
+        assert_eq!(closure.are_neighbors(&'e', &'f'), true);
+        assert_eq!(closure.are_neighbors(&'a', &'a'), false);
+        assert_eq!(closure.out_degree(&'d'), Some(0));
+    }
+
+    #[test]
+    fn closure_of_cycles() {
+        let mut g: DiGraph<u32> = DiGraph::new();
+        g.add_vertex(1).expect("Adding a unique vertex should've worked!");
+        g.add_edge(&1, &2).expect("Adding this edge should've worked!");
+        g.add_edge(&2, &1).expect("Adding this edge should've worked!");
+        g.add_edge(&2, &3).expect("Adding this edge should've worked!");
+        g.add_edge(&3, &3).expect("Adding this edge should've worked!");
+        g.add_edge(&3, &4).expect("Adding this edge should've worked!");
+
+        // Only vertices on a cycle reach themselves
+        let closure = transitive_closure(&g);
+        assert_eq!(closure.neighbors_of(&1).unwrap().eq([1, 2, 3, 4].iter()), true);
+        assert_eq!(closure.neighbors_of(&2).unwrap().eq([1, 2, 3, 4].iter()), true);
+        assert_eq!(closure.neighbors_of(&3).unwrap().eq([3, 4].iter()), true);
+        assert_eq!(closure.out_degree(&4), Some(0));
+    }
+
+    #[test]
+    fn reduction_of_dag() {
+        let g = helper_make_dag();
+
+        let reduction = transitive_reduction(&g).unwrap();
+        assert_eq!(reduction.num_vertices(), 6);
+        assert_eq!(reduction.num_edges(), 4);
+        assert_eq!(reduction.neighbors_of(&'a').unwrap().eq(['b'].iter()), true);
+        assert_eq!(reduction.neighbors_of(&'b').unwrap().eq(['c'].iter()), true);
+        assert_eq!(reduction.neighbors_of(&'c').unwrap().eq(['d'].iter()), true);
+        assert_eq!(reduction.are_neighbors(&'e', &'f'), true);
+    }
+
+    #[test]
+    fn reduction_keeps_payloads_and_drops_parallel_edges() {
+        let mut g: DiGraph<u32, &str> = DiGraph::new();
+        g.add_vertex(1).expect("Adding a unique vertex should've worked!");
+        g.add_edge_with(&1, &2, "first").expect("Adding this edge should've worked!");
+        g.add_edge_with(&1, &2, "second").expect("Adding this edge should've worked!");
+        g.add_edge_with(&2, &3, "needed").expect("Adding this edge should've worked!");
+        g.add_edge_with(&1, &3, "redundant").expect("Adding this edge should've worked!");
+
+        let reduction = transitive_reduction(&g).unwrap();
+        assert_eq!(reduction.num_edges(), 2);
+        assert_eq!(reduction.edge_weight(&1, &2), Some(&"first"));
+        assert_eq!(reduction.edge_weight(&2, &3), Some(&"needed"));
+    }
+
+    #[test]
+    fn reduction_of_cyclic_graph_is_err() {
+        let mut g: DiGraph<u32> = DiGraph::new();
+        g.add_vertex(1).expect("Adding a unique vertex should've worked!");
+        g.add_edge(&1, &2).expect("Adding this edge should've worked!");
+        g.add_edge(&2, &3).expect("Adding this edge should've worked!");
+        g.add_edge(&3, &1).expect("Adding this edge should've worked!");
+
+        match transitive_reduction(&g) {
+            Ok(_) => panic!("A cyclic graph has no transitive reduction!"),
+            Err(err) => assert_eq!(err.cycle.len(), 3)
+        }
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        let mut rng = LFSR::new();
+        let n = 60;
+
+        for _ in 0..10 {
+            let mut g: DiGraph<u32> = DiGraph::new();
+            for v in 0..n {
+                g.add_vertex(v).expect("Adding a unique vertex should've worked!");
+            }
+            for _ in 0..150 {
+                let u = rng.rand() % n;
+                let v = rng.rand() % n;
+                // Only edges going forward, so this is a DAG
+                if u < v {
+                    g.add_edge(&u, &v).expect("Adding this edge should've worked!");
+                }
+            }
+
+            let closure = transitive_closure(&g);
+            let reduction = transitive_reduction(&g).unwrap();
+            for u in 0..n {
+                let reachable = helper_reachable(&g, u);
+                let closure_edges: HashSet<u32> = closure.neighbors_of(&u).unwrap().cloned().collect();
+                assert_eq!(closure_edges, reachable);
+                assert_eq!(helper_reachable(&reduction, u), reachable);
+
+                // Every edge left is needed: nothing else gets to its target
+                for v in reduction.neighbors_of(&u).unwrap() {
+                    let others: HashSet<u32> = reduction.neighbors_of(&u).unwrap()
+                        .filter(|w| *w != v)
+                        .flat_map(|w| helper_reachable(&reduction, *w).into_iter().chain([*w]))
+                        .collect();
+                    assert_eq!(others.contains(v), false);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,170 @@
+#[cfg(test)]
+mod traversal {
+    use dsa_in_rust::graphs::digraph::DiGraph;
+    use dsa_in_rust::graphs::error::GraphError;
+    use dsa_in_rust::graphs::traversal::*;
+
+    fn helper_make_graph() -> DiGraph<i64> {
+        let mut g: DiGraph<i64> = DiGraph::new();
+
+        // 1 -> 2 -> 4 -> 6
+        // 1 -> 3 -> 4, 3 -> 5, 6 -> 2
+        // and a separate 7 -> 8 -> 5
+        g.add_vertex(1).expect("Adding a unique vertex should've worked!");
+        g.add_edge(&1, &2).expect("Adding this edge should've worked!");
+        g.add_edge(&1, &3).expect("Adding this edge should've worked!");
+        g.add_edge(&2, &4).expect("Adding this edge should've worked!");
+        g.add_edge(&3, &4).expect("Adding this edge should've worked!");
+        g.add_edge(&3, &5).expect("Adding this edge should've worked!");
+        g.add_edge(&4, &6).expect("Adding this edge should've worked!");
+        g.add_edge(&6, &2).expect("Adding this edge should've worked!");
+        g.add_vertex(7).expect("Adding a unique vertex should've worked!");
+        g.add_edge(&7, &8).expect("Adding this edge should've worked!");
+        g.add_edge(&8, &5).expect("Adding this edge should've worked!");
+
+        g
+    }
+
+    #[test]
+    fn bfs_visits_reachable_vertices_by_distance() {
+        let g = helper_make_graph();
+
+        let visited: Vec<i64> = Bfs::new(&g, &1).unwrap().cloned().collect();
+        assert_eq!(visited, vec![1, 2, 3, 4, 5, 6]);
+
+        let visited: Vec<i64> = Bfs::new(&g, &7).unwrap().cloned().collect();
+        assert_eq!(visited, vec![7, 8, 5]);
+    }
+
+    #[test]
+    fn dfs_visits_reachable_vertices_in_preorder() {
+        let g = helper_make_graph();
+
+        let visited: Vec<i64> = Dfs::new(&g, &1).unwrap().cloned().collect();
+        assert_eq!(visited, vec![1, 2, 4, 6, 3, 5]);
+
+        let visited: Vec<i64> = Dfs::new(&g, &3).unwrap().cloned().collect();
+        assert_eq!(visited, vec![3, 4, 6, 2, 5]);
+    }
+
+    #[test]
+    fn full_traversals_cover_every_vertex() {
+        let g = helper_make_graph();
+
+        let visited: Vec<i64> = Bfs::full(&g).cloned().collect();
+        assert_eq!(visited, vec![1, 2, 3, 4, 5, 6, 7, 8]);
+
+        let visited: Vec<i64> = Dfs::full(&g).cloned().collect();
+        assert_eq!(visited, vec![1, 2, 4, 6, 3, 5, 7, 8]);
+    }
+
+    #[test]
+    fn traversals_are_lazy() {
+        let mut g: DiGraph<i64> = DiGraph::new();
+
+        g.add_vertex(0).expect("Adding a unique vertex should've worked!");
+        for i in 0..100_000 {
+            g.add_edge(&i, &(i + 1)).expect("Adding this edge should've worked!");
+        }
+
+        let visited: Vec<i64> = Dfs::new(&g, &0).unwrap().take(3).cloned().collect();
+        assert_eq!(visited, vec![0, 1, 2]);
+        assert_eq!(Dfs::new(&g, &0).unwrap().count(), 100_001);
+        assert_eq!(Bfs::new(&g, &0).unwrap().last(), Some(&100_000));
+    }
+
+    #[test]
+    fn traversal_from_missing_vertex_is_err() {
+        let g = helper_make_graph();
+
+        assert!(matches!(Bfs::new(&g, &42), Err(GraphError::MissingVertex)));
+        assert!(matches!(Dfs::new(&g, &42), Err(GraphError::MissingVertex)));
+        assert_eq!(depth_first_visit(&g, &42, |_| {}), Err(GraphError::MissingVertex));
+    }
+
+    #[test]
+    fn visitor_classifies_edges() {
+        let g = helper_make_graph();
+
+        let mut events: Vec<DfsEvent<i64>> = Vec::new();
+        depth_first_visit(&g, &1, |e| events.push(e)).unwrap();
+
+        assert_eq!(events, vec![
+            DfsEvent::Discover(&1),
+            DfsEvent::TreeEdge(&1, &2),
+            DfsEvent::Discover(&2),
+            DfsEvent::TreeEdge(&2, &4),
+            DfsEvent::Discover(&4),
+            DfsEvent::TreeEdge(&4, &6),
+            DfsEvent::Discover(&6),
+            DfsEvent::BackEdge(&6, &2),
+            DfsEvent::Finish(&6),
+            DfsEvent::Finish(&4),
+            DfsEvent::Finish(&2),
+            DfsEvent::TreeEdge(&1, &3),
+            DfsEvent::Discover(&3),
+            DfsEvent::CrossEdge(&3, &4),
+            DfsEvent::TreeEdge(&3, &5),
+            DfsEvent::Discover(&5),
+            DfsEvent::Finish(&5),
+            DfsEvent::Finish(&3),
+            DfsEvent::Finish(&1),
+        ]);
+    }
+
+    #[test]
+    fn visitor_finds_forward_edges() {
+        let mut g: DiGraph<i64> = DiGraph::new();
+
+        g.add_vertex(1).expect("Adding a unique vertex should've worked!");
+        g.add_edge(&1, &2).expect("Adding this edge should've worked!");
+        g.add_edge(&2, &3).expect("Adding this edge should've worked!");
+        g.add_edge(&1, &3).expect("Adding this edge should've worked!");
+
+        let mut forward: Vec<(i64, i64)> = Vec::new();
+        depth_first_visit(&g, &1, |e| {
+            if let DfsEvent::ForwardEdge(u, v) = e {
+                forward.push((*u, *v));
+            }
+        }).unwrap();
+        assert_eq!(forward, vec![(1, 3)]);
+    }
+
+    #[test]
+    fn visitor_gives_pre_and_post_order() {
+        let g = helper_make_graph();
+
+        let mut preorder: Vec<i64> = Vec::new();
+        let mut postorder: Vec<i64> = Vec::new();
+        depth_first_visit_all(&g, |e| match e {
+            DfsEvent::Discover(v) => preorder.push(*v),
+            DfsEvent::Finish(v) => postorder.push(*v),
+            _ => {}
+        });
+
+        assert_eq!(preorder, Dfs::full(&g).cloned().collect::<Vec<i64>>());
+        assert_eq!(postorder, vec![6, 4, 2, 5, 3, 1, 8, 7]);
+    }
+
+    #[test]
+    fn start_vertex_can_be_a_temporary() {
+        let mut g: DiGraph<String> = DiGraph::new();
+        g.add_vertex("a".to_string()).expect("Adding a unique vertex should've worked!");
+        g.add_edge(&"a".to_string(), &"b".to_string()).expect("Adding this edge should've worked!");
+
+        // None of these borrow the start vertex for as long as the traversal lives
+        let bfs = Bfs::new(&g, &"a".to_string()).unwrap();
+        let dfs = Dfs::new(&g, &"a".to_string()).unwrap();
+        assert_eq!(bfs.collect::<Vec<_>>(), vec!["a", "b"]);
+        assert_eq!(dfs.collect::<Vec<_>>(), vec!["a", "b"]);
+
+        let mut discovered: Vec<&String> = Vec::new();
+        depth_first_visit(&g, &"b".to_string(), |e| {
+            if let DfsEvent::Discover(v) = e {
+                discovered.push(v);
+            }
+        }).unwrap();
+        assert_eq!(discovered, vec!["b"]);
+        assert_eq!(Bfs::new(&g, &"c".to_string()).err(), Some(GraphError::MissingVertex));
+    }
+}
@@ -0,0 +1,48 @@
+#![allow(clippy::bool_assert_comparison)]
+
+#[cfg(test)]
+mod union_find {
+    use dsa_in_rust::misc::union_find::*;
+
+    #[test]
+    fn starts_as_singletons() {
+        let mut uf = UnionFind::new(4);
+        assert_eq!(uf.len(), 4);
+        assert_eq!(uf.num_sets(), 4);
+        for x in 0..4 {
+            assert_eq!(uf.find(x), x);
+        }
+        assert_eq!(uf.connected(0, 1), false);
+
+        assert_eq!(UnionFind::new(0).is_empty(), true);
+    }
+
+    #[test]
+    fn union_merges_sets() {
+        let mut uf = UnionFind::new(6);
+
+        assert_eq!(uf.union(0, 1), true);
+        assert_eq!(uf.union(2, 3), true);
+        assert_eq!(uf.union(1, 3), true);
+        assert_eq!(uf.num_sets(), 3);
+        assert_eq!(uf.connected(0, 2), true);
+        assert_eq!(uf.connected(3, 0), true);
+        assert_eq!(uf.connected(0, 4), false);
+
+        // Already together, so nothing changes
+        assert_eq!(uf.union(2, 0), false);
+        assert_eq!(uf.num_sets(), 3);
+    }
+
+    #[test]
+    fn long_chains_are_handled() {
+        let n = 100_000;
+        let mut uf = UnionFind::new(n);
+
+        for x in 1..n {
+            uf.union(x - 1, x);
+        }
+        assert_eq!(uf.num_sets(), 1);
+        assert_eq!(uf.connected(0, n - 1), true);
+    }
+}